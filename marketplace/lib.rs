@@ -1,24 +1,66 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+pub use self::marketplace::{Marketplace, MarketplaceRef};
+
 #[ink::contract]
-mod marketplace {
+pub mod marketplace {
     use ink::prelude::string::String;
     use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
 
+    //Cantidad maxima de elementos que retorna una consulta paginada
+    pub const MAX_LIMITE_PAGINA: u32 = 50;
+
+    //Largo permitido de un username, en caracteres
+    pub const MIN_LARGO_USERNAME: usize = 3;
+    pub const MAX_LARGO_USERNAME: usize = 32;
+
+    //Largo maximo del nombre de una categoria, en caracteres
+    pub const MAX_LARGO_NOMBRE_CATEGORIA: usize = 32;
+
+    //Categorias que crea el constructor, con ids 0 a 3
+    pub const CATEGORIAS_INICIALES: [&str; 4] = ["Computacion", "Ropa", "Herramientas", "Muebles"];
+
     #[ink(storage)]
     pub struct Marketplace {
+        owner: AccountId,                             // Cuenta que despliega el contrato
+        owner_pendiente: Option<AccountId>, // Nuevo owner que todavia no acepto la transferencia
+        admins: Mapping<AccountId, ()>, // Cuentas con permisos de administracion ademas del owner
+        suspensiones: Mapping<AccountId, Suspension>, // (id_usuario, suspension impuesta por un admin)
         usuarios: Mapping<AccountId, Usuario>, // (id_usuario, datos_usuario) este capaz tmbn tenga
         // que ser un vec y un mapping aparte, depende de lo que necesitemos
         // pq si queremos obtener todos los usuarios y mostrarlos sonamos
 
-        // storage general y mapping para mejorar performance
-        publicaciones: Vec<Publicacion>,
-        ordenes_compra: Vec<OrdenCompra>,
+        // storage general en mappings indexados por id + contadores, asi cada mensaje
+        // carga solo las entradas que usa y no todo el catalogo
+        // u32 parece ser la mejor opción, usize no existe en ink porque depende de la arquitectura
+        // u64 incrementaría los costos de transacción
+        publicaciones: Mapping<u32, Publicacion>, // (id_publicacion, publicacion)
+        cantidad_publicaciones: u32,
+        ordenes_compra: Mapping<u32, OrdenCompra>, // (id_orden, orden)
+        cantidad_ordenes: u32,
         publicaciones_mapping: Mapping<AccountId, Vec<u32>>, // (id_vendedor, id's publicaciones)
         ordenes_compra_mapping: Mapping<AccountId, Vec<u32>>, // (id_comprador, id's ordenes)
-                                                             // u32 parece ser la mejor opción, usize no existe en ink porque depende de la arquitectura
-                                                             // u64 incrementaría los costos de transacción
+        // indice de ordenes por vendedor con una entrada por orden, asi una compra
+        // no reescribe la lista entera de un vendedor con muchas ventas
+        ordenes_vendedor_mapping: Mapping<(AccountId, u32), u32>, // ((id_vendedor, posicion), id_orden)
+        cantidad_ordenes_vendedor: Mapping<AccountId, u32>, // (id_vendedor, cantidad de ordenes)
+        ordenes_publicacion: Mapping<(u32, u32), u32>, // ((id_publicacion, posicion), id_orden)
+        cantidad_ordenes_publicacion: Mapping<u32, u32>, // (id_publicacion, cantidad de ordenes)
+        fondos_retenidos: Mapping<u32, Balance>,       // (id_orden, monto pagado por el comprador)
+        reputaciones: Mapping<AccountId, Reputacion>,  // (id_usuario, calificaciones recibidas)
+        usernames: Mapping<String, AccountId>, // (username, id_usuario) para que sean unicos
+        categorias: Mapping<u32, Categoria>,   // (id_categoria, categoria)
+        cantidad_categorias: u32,
+        nombres_categorias: Mapping<String, u32>, // (nombre, id_categoria) para que sean unicos
+        publicaciones_categoria: Mapping<u32, Vec<u32>>, // (id_categoria, id's publicaciones)
+        // indice de usuarios en orden de registro y contadores de sus ordenes, asi los
+        // reportes recorren usuarios de a paginas sin leer el historial de ordenes
+        usuarios_registrados: Mapping<u32, AccountId>, // (posicion, id_usuario)
+        cantidad_usuarios: u32,
+        contadores_usuarios: Mapping<AccountId, ContadoresUsuario>, // (id_usuario, contadores)
+        contadores_categorias: Mapping<u32, ContadoresVentas>,      // (id_categoria, contadores)
+        contadores_ventas: ContadoresVentas, // Totales de todas las categorias
     }
 
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -33,17 +75,52 @@ mod marketplace {
         VendedorSinPublicaciones,
         PublicacionSinStock,
         PublicacionNoExistente,
-        UnderflowPublicaciones,
-        UnderflowOrdenes,
+        OverflowPublicaciones,
+        OverflowOrdenes,
+        OrdenNoExistente,
+        NoEsVendedorDeLaOrden,
+        NoEsCompradorDeLaOrden,
+        OrdenNoPendiente,
+        OrdenNoEnviada,
+        OrdenYaRecibida,
+        OrdenCancelada,
+        CancelacionYaSolicitada,
+        CancelacionNoSolicitada,
+        CancelacionPendiente,
+        OverflowStock,
+        MontoIncorrecto,
+        TransferenciaFallida,
+        CantidadInvalida,
+        OverflowPrecioTotal,
+        NoEsVendedorDeLaPublicacion,
+        PublicacionPausada,
+        PublicacionYaActiva,
+        PuntajeInvalido,
+        OrdenNoRecibida,
+        OrdenYaCalificada,
+        VendedorConPublicacionesActivas,
+        UsuarioConOrdenesEnCurso,
+        UsernameLargoInvalido,
+        UsernameCaracterInvalido,
+        UsernameEnUso,
+        NoAutorizado,
+        CategoriaNoExistente,
+        NombreCategoriaInvalido,
+        OverflowCategorias,
+        UsuarioSuspendido,
+        PublicacionDadaDeBaja,
+        NombreCategoriaEnUso,
+        OverflowUsuarios,
     }
 
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
     #[derive(Debug, Clone, PartialEq)]
     pub struct Usuario {
-        username: String,
-        rol: Rol,
-        account_id: AccountId,
+        pub username: String,
+        pub rol: Rol,
+        pub account_id: AccountId,
+        pub bloque_registro: BlockNumber,
     }
 
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -59,34 +136,49 @@ mod marketplace {
     #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
     #[derive(Debug, Clone, PartialEq)]
     pub struct Publicacion {
-        id_publicacion: u64,
-        nombre_producto: String,
-        descripcion: String,
-        precio: u64,
-        categoria: Categoria,
-        stock: u64,
-        vendedor_id: AccountId,
+        pub id_publicacion: u64,
+        pub nombre_producto: String,
+        pub descripcion: String,
+        pub precio: u64,
+        pub id_categoria: u32,
+        pub stock: u64,
+        pub vendedor_id: AccountId,
+        pub activa: bool, // Las publicaciones pausadas no se listan ni aceptan ordenes
+        pub unidades_vendidas: u64, // Unidades en ordenes no canceladas
+        pub bloque_creacion: BlockNumber,
+        // Dada de baja por un admin: queda inactiva y el vendedor no la puede reanudar
+        pub dada_de_baja: bool,
     }
 
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
     #[derive(Debug, Clone, PartialEq)]
-    pub enum Categoria {
-        Computacion,
-        Ropa,
-        Herramientas,
-        Muebles,
+    pub struct Categoria {
+        pub id_categoria: u32,
+        pub nombre: String,
+        pub id_padre: Option<u32>, // Categoria de la que es subcategoria
     }
 
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
     #[derive(Debug, Clone, PartialEq)]
     pub struct OrdenCompra {
-        estado: Estado,
-        publicacion: Publicacion,
-        comprador_id: AccountId,
-        peticion_cancelacion: bool, // La peticion la hace el comprador, el vendedor acepta, esta
-                                    // logica se maneja en el método (?)
+        pub estado: Estado,
+        pub id_publicacion: u32,
+        pub vendedor_id: AccountId,
+        pub comprador_id: AccountId,
+        // Snapshot de lo acordado al ordenar, no cambia si se edita la publicacion
+        pub nombre_producto: String,
+        pub precio_unitario: u64,
+        pub cantidad: u64,
+        // La peticion la hace el comprador con solicitar_cancelacion,
+        // el vendedor la acepta o la rechaza
+        pub peticion_cancelacion: bool,
+        // Queda en true desde la primera peticion, aunque despues se resuelva
+        pub cancelacion_solicitada: bool,
+        // Cada parte puede calificar a la otra una sola vez, con la orden recibida
+        pub calificada_por_comprador: bool,
+        pub calificada_por_vendedor: bool,
     }
 
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -99,21 +191,369 @@ mod marketplace {
         Cancelada,
     }
 
+    //Suma y cantidad de las calificaciones que recibio un usuario, sea como
+    //comprador o como vendedor
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct Reputacion {
+        pub puntaje_total: u32,
+        pub cantidad_calificaciones: u32,
+    }
+
+    //Datos publicos de un usuario, visibles para cualquier otro usuario
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Perfil {
+        pub account_id: AccountId,
+        pub username: String,
+        pub rol: Rol,
+        pub reputacion: Reputacion,
+        pub cantidad_publicaciones: u32, // Solo las activas, sin pausadas ni dadas de baja
+        pub bloque_registro: BlockNumber,
+    }
+
+    //Suspension de un usuario. Sin bloque de fin dura hasta que un admin la levanta
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Suspension {
+        pub motivo: String,
+        pub hasta_bloque: Option<BlockNumber>, // Primer bloque en el que ya no esta suspendido
+    }
+
+    //Orden de compra junto con el estado actual de su publicacion
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct OrdenDetalle {
+        pub orden: OrdenCompra,
+        pub publicacion: Publicacion,
+    }
+
+    //Contadores de las ordenes de un usuario, se actualizan cuando cambia el
+    //estado de cada orden. Las ventas se cuentan al recibirse y las compras desde
+    //que se crean hasta que se cancelan
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct ContadoresUsuario {
+        pub ventas: u32, // Todas las ordenes del usuario como vendedor
+        pub ventas_canceladas: u32,
+        pub ventas_con_peticion_cancelacion: u32, // Una vez por orden, aunque se resuelva
+        pub ventas_recibidas: u32, // Ordenes del usuario como vendedor marcadas como recibidas
+        pub total_vendido: Balance,
+        pub compras: u32, // Ordenes del usuario como comprador que no fueron canceladas
+        pub total_gastado: Balance, // Suma de precio * cantidad de esas mismas ordenes
+    }
+
+    //Publicaciones activas y ventas recibidas de una categoria o del Marketplace
+    //entero, se actualizan con cada cambio en vez de recorrer el catalogo
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct ContadoresVentas {
+        pub publicaciones_activas: u32,
+        pub ordenes_recibidas: u32,
+        pub unidades_vendidas: u64,
+        pub total_vendido: Balance,
+    }
+
+    //Contadores de una categoria junto con sus datos, para reportes
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct VentasCategoria {
+        pub categoria: Categoria,
+        pub contadores: ContadoresVentas,
+    }
+
+    //Totales del Marketplace, para reportes
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct ResumenVentas {
+        pub cantidad_publicaciones: u32,
+        pub cantidad_ordenes: u32,
+        pub contadores: ContadoresVentas,
+    }
+
+    //Contadores de un usuario junto con su username, para reportes
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ResumenUsuario {
+        pub account_id: AccountId,
+        pub username: String,
+        pub contadores: ContadoresUsuario,
+    }
+
+    //Resultado de una consulta paginada: los elementos de la pagina y el total
+    //de posiciones a recorrer para poder pedir las siguientes. Si la consulta
+    //omite entradas ocultas, `total` cuenta tambien las omitidas
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Pagina<T> {
+        pub elementos: Vec<T>,
+        pub total: u32,
+    }
+
+    //Filtros de buscar_publicaciones. Los campos en None no filtran
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct FiltroPublicaciones {
+        pub id_categoria: Option<u32>, // Incluye las publicaciones de sus subcategorias
+        pub precio_min: Option<u64>,
+        pub precio_max: Option<u64>,
+        pub solo_con_stock: bool,
+        pub vendedor_id: Option<AccountId>,
+        pub ordenar_por: Option<OrdenPublicaciones>, // None mantiene el orden de creacion
+    }
+
+    //Criterios de ordenamiento del catalogo. Los empates se resuelven por id
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum OrdenPublicaciones {
+        PrecioAscendente,
+        PrecioDescendente,
+        MasRecientes,
+        MasVendidas,
+    }
+
+    impl OrdenPublicaciones {
+        //Ordena las publicaciones segun el criterio
+        fn ordenar(&self, publicaciones: &mut [Publicacion]) {
+            match self {
+                OrdenPublicaciones::PrecioAscendente => {
+                    publicaciones.sort_by_key(|p| (p.precio, p.id_publicacion))
+                }
+                OrdenPublicaciones::PrecioDescendente => publicaciones.sort_by(|a, b| {
+                    b.precio
+                        .cmp(&a.precio)
+                        .then(a.id_publicacion.cmp(&b.id_publicacion))
+                }),
+                OrdenPublicaciones::MasRecientes => publicaciones.sort_by(|a, b| {
+                    (b.bloque_creacion, b.id_publicacion)
+                        .cmp(&(a.bloque_creacion, a.id_publicacion))
+                }),
+                OrdenPublicaciones::MasVendidas => publicaciones.sort_by(|a, b| {
+                    b.unidades_vendidas
+                        .cmp(&a.unidades_vendidas)
+                        .then(a.id_publicacion.cmp(&b.id_publicacion))
+                }),
+            }
+        }
+    }
+
+    impl FiltroPublicaciones {
+        //Indica si la publicacion cumple con los filtros de precio, stock y vendedor.
+        //La categoria la filtra buscar_publicaciones porque incluye subcategorias
+        fn acepta(&self, publicacion: &Publicacion) -> bool {
+            self.precio_min.iter().all(|&min| publicacion.precio >= min)
+                && self.precio_max.iter().all(|&max| publicacion.precio <= max)
+                && (!self.solo_con_stock || publicacion.stock > 0)
+                && self
+                    .vendedor_id
+                    .iter()
+                    .all(|&vendedor_id| publicacion.vendedor_id == vendedor_id)
+        }
+    }
+
+    //Eventos emitidos en cada cambio de estado del marketplace
+
+    #[ink(event)]
+    pub struct UsuarioRegistrado {
+        #[ink(topic)]
+        account_id: AccountId,
+        username: String,
+        rol: Rol,
+    }
+
+    #[ink(event)]
+    pub struct UsuarioActualizado {
+        #[ink(topic)]
+        account_id: AccountId,
+        username: String,
+        rol: Rol,
+    }
+
+    #[ink(event)]
+    pub struct PublicacionCreada {
+        #[ink(topic)]
+        vendedor_id: AccountId,
+        idx_publicacion: u32,
+        nombre_producto: String,
+        precio: u64,
+        id_categoria: u32,
+        stock: u64,
+    }
+
+    #[ink(event)]
+    pub struct AdminAgregado {
+        #[ink(topic)]
+        account_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct AdminQuitado {
+        #[ink(topic)]
+        account_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipPropuesta {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        nuevo_owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferido {
+        #[ink(topic)]
+        owner_anterior: AccountId,
+        #[ink(topic)]
+        owner_nuevo: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct UsuarioSuspendido {
+        #[ink(topic)]
+        account_id: AccountId,
+        motivo: String,
+        hasta_bloque: Option<BlockNumber>,
+    }
+
+    #[ink(event)]
+    pub struct SuspensionLevantada {
+        #[ink(topic)]
+        account_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct PublicacionDadaDeBaja {
+        #[ink(topic)]
+        vendedor_id: AccountId,
+        idx_publicacion: u32,
+        motivo: String,
+        ordenes_canceladas: u32,
+    }
+
+    #[ink(event)]
+    pub struct CategoriaCreada {
+        #[ink(topic)]
+        id_categoria: u32,
+        nombre: String,
+        id_padre: Option<u32>,
+    }
+
+    #[ink(event)]
+    pub struct CategoriaRenombrada {
+        #[ink(topic)]
+        id_categoria: u32,
+        nombre: String,
+    }
+
+    #[ink(event)]
+    pub struct PublicacionActualizada {
+        #[ink(topic)]
+        vendedor_id: AccountId,
+        idx_publicacion: u32,
+        precio: u64,
+        stock: u64,
+        activa: bool,
+    }
+
+    #[ink(event)]
+    pub struct OrdenCreada {
+        #[ink(topic)]
+        comprador_id: AccountId,
+        #[ink(topic)]
+        vendedor_id: AccountId,
+        idx_orden: u32,
+        idx_publicacion: u32,
+        cantidad: u64,
+        monto: Balance,
+    }
+
+    #[ink(event)]
+    pub struct EstadoOrdenCambiado {
+        #[ink(topic)]
+        comprador_id: AccountId,
+        #[ink(topic)]
+        vendedor_id: AccountId,
+        idx_orden: u32,
+        estado_anterior: Estado,
+        estado_nuevo: Estado,
+    }
+
+    #[ink(event)]
+    pub struct CancelacionSolicitada {
+        #[ink(topic)]
+        comprador_id: AccountId,
+        #[ink(topic)]
+        vendedor_id: AccountId,
+        idx_orden: u32,
+    }
+
+    #[ink(event)]
+    pub struct CancelacionResuelta {
+        #[ink(topic)]
+        comprador_id: AccountId,
+        #[ink(topic)]
+        vendedor_id: AccountId,
+        idx_orden: u32,
+        aceptada: bool,
+    }
+
+    #[ink(event)]
+    pub struct CalificacionRegistrada {
+        #[ink(topic)]
+        calificador_id: AccountId,
+        #[ink(topic)]
+        calificado_id: AccountId,
+        idx_orden: u32,
+        puntaje: u8,
+    }
+
     impl Marketplace {
         #[ink(constructor)]
         pub fn new() -> Self {
-            Self {
+            let mut marketplace = Self {
+                owner: Self::env().caller(),
+                owner_pendiente: None,
+                admins: Default::default(),
+                suspensiones: Default::default(),
                 usuarios: Default::default(),
                 publicaciones: Default::default(),
+                cantidad_publicaciones: 0,
                 ordenes_compra: Default::default(),
+                cantidad_ordenes: 0,
                 publicaciones_mapping: Default::default(),
                 ordenes_compra_mapping: Default::default(),
+                ordenes_vendedor_mapping: Default::default(),
+                cantidad_ordenes_vendedor: Default::default(),
+                ordenes_publicacion: Default::default(),
+                cantidad_ordenes_publicacion: Default::default(),
+                fondos_retenidos: Default::default(),
+                reputaciones: Default::default(),
+                usernames: Default::default(),
+                categorias: Default::default(),
+                cantidad_categorias: 0,
+                nombres_categorias: Default::default(),
+                publicaciones_categoria: Default::default(),
+                usuarios_registrados: Default::default(),
+                cantidad_usuarios: 0,
+                contadores_usuarios: Default::default(),
+                contadores_categorias: Default::default(),
+                contadores_ventas: Default::default(),
+            };
+
+            for nombre in CATEGORIAS_INICIALES {
+                let _ = marketplace.agregar_categoria(nombre.into(), None);
             }
+
+            marketplace
         }
 
         //Registra usuarios que no estan en el sistema
         #[ink(message)]
-        #[ignore]
         pub fn registrar_usuario(
             &mut self,
             username: String,
@@ -134,22 +574,43 @@ mod marketplace {
                 return Err(ErrorSistema::UsuarioYaRegistrado);
             };
 
+            //Valida el username y lo reserva para el usuario
+            validar_username(&username)?;
+            if self.usernames.contains(&username) {
+                return Err(ErrorSistema::UsernameEnUso);
+            }
+
+            //Calcula la posicion del usuario en el indice de registrados
+            let posicion = self.cantidad_usuarios;
+            self.cantidad_usuarios = posicion
+                .checked_add(1)
+                .ok_or(ErrorSistema::OverflowUsuarios)?;
+
+            self.usernames.insert(&username, &caller);
+            self.usuarios_registrados.insert(posicion, &caller);
+
             //Crea el nuevo usuario
             let usuario = Usuario {
                 account_id: caller,
                 username,
                 rol,
+                bloque_registro: self.env().block_number(),
             };
 
             //Almacena el nuevo usuario en el sistema
             self.usuarios.insert(caller, &usuario);
 
+            self.env().emit_event(UsuarioRegistrado {
+                account_id: caller,
+                username: usuario.username.clone(),
+                rol: usuario.rol.clone(),
+            });
+
             Ok(usuario)
         }
 
         //Retorna los datos de un usuario si existe en el sistema
         #[ink(message)]
-        #[ignore]
         pub fn get_usuario(&self) -> Result<Usuario, ErrorSistema> {
             self._get_usuario(self.env().caller())
         }
@@ -161,15 +622,119 @@ mod marketplace {
                 .ok_or(ErrorSistema::UsuarioNoRegistrado)
         }
 
+        //Retorna el perfil publico de cualquier usuario registrado
+        #[ink(message)]
+        pub fn get_perfil(&self, account_id: AccountId) -> Result<Perfil, ErrorSistema> {
+            let usuario = self._get_usuario(account_id)?;
+
+            //Cuenta solo las publicaciones que el resto de los usuarios puede ver
+            let cantidad_publicaciones = self
+                .publicaciones_mapping
+                .get(account_id)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|&i| self.publicaciones.get(i))
+                .filter(|publicacion| publicacion.activa)
+                .count() as u32;
+
+            Ok(Perfil {
+                account_id,
+                username: usuario.username,
+                rol: usuario.rol,
+                reputacion: self.reputaciones.get(account_id).unwrap_or_default(),
+                cantidad_publicaciones,
+                bloque_registro: usuario.bloque_registro,
+            })
+        }
+
+        //Cambia el rol del usuario solicitante. No se puede dejar de ser vendedor
+        //con publicaciones activas con stock ni dejar un rol con ordenes en curso
+        #[ink(message)]
+        pub fn cambiar_rol(&mut self, nuevo_rol: Rol) -> Result<Usuario, ErrorSistema> {
+            self._cambiar_rol(self.env().caller(), nuevo_rol)
+        }
+
+        //Funcion prueba cambiar_rol()
+        fn _cambiar_rol(
+            &mut self,
+            caller: AccountId,
+            nuevo_rol: Rol,
+        ) -> Result<Usuario, ErrorSistema> {
+            //Validacion de usuario
+            let mut usuario = self._get_usuario(caller)?;
+
+            //Deja de ser vendedor
+            if usuario.es_vendedor().is_ok() && matches!(nuevo_rol, Rol::Comprador) {
+                let tiene_publicaciones_activas = self
+                    .publicaciones_mapping
+                    .get(caller)
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|&i| self.publicaciones.get(i))
+                    .any(|publicacion| publicacion.activa && publicacion.stock > 0);
+                if tiene_publicaciones_activas {
+                    return Err(ErrorSistema::VendedorConPublicacionesActivas);
+                }
+                if self.tiene_ordenes_en_curso(&self.ids_ordenes_vendedor(caller)) {
+                    return Err(ErrorSistema::UsuarioConOrdenesEnCurso);
+                }
+            }
+
+            //Deja de ser comprador
+            if usuario.es_comprador().is_ok()
+                && matches!(nuevo_rol, Rol::Vendedor)
+                && self.tiene_ordenes_en_curso(
+                    &self.ordenes_compra_mapping.get(caller).unwrap_or_default(),
+                )
+            {
+                return Err(ErrorSistema::UsuarioConOrdenesEnCurso);
+            }
+
+            usuario.rol = nuevo_rol;
+            self.actualizar_usuario(&usuario);
+
+            Ok(usuario)
+        }
+
+        //Cambia el username del usuario solicitante
+        #[ink(message)]
+        pub fn editar_username(&mut self, username: String) -> Result<Usuario, ErrorSistema> {
+            self._editar_username(self.env().caller(), username)
+        }
+
+        //Funcion prueba editar_username()
+        fn _editar_username(
+            &mut self,
+            caller: AccountId,
+            username: String,
+        ) -> Result<Usuario, ErrorSistema> {
+            //Validacion de usuario
+            let mut usuario = self._get_usuario(caller)?;
+
+            //Valida el username nuevo. Si es el mismo que ya tiene no hay nada que reservar
+            validar_username(&username)?;
+            if usuario.username != username {
+                if self.usernames.contains(&username) {
+                    return Err(ErrorSistema::UsernameEnUso);
+                }
+                self.usernames.remove(&usuario.username);
+                self.usernames.insert(&username, &caller);
+            }
+
+            usuario.username = username;
+            self.actualizar_usuario(&usuario);
+
+            Ok(usuario)
+        }
+
         //Crea una publicacion
         #[ink(message)]
-        #[ignore]
         pub fn publicar(
             &mut self,
             nombre_producto: String,
             descripcion: String,
             precio: u64,
-            categoria: Categoria,
+            id_categoria: u32,
             stock: u64,
         ) -> Result<Publicacion, ErrorSistema> {
             self._publicar(
@@ -177,7 +742,7 @@ mod marketplace {
                 nombre_producto,
                 descripcion,
                 precio,
-                categoria,
+                id_categoria,
                 stock,
             )
         }
@@ -189,80 +754,158 @@ mod marketplace {
             nombre_producto: String,
             descripcion: String,
             precio: u64,
-            categoria: Categoria,
+            id_categoria: u32,
             stock: u64,
         ) -> Result<Publicacion, ErrorSistema> {
             //Validacion de usuario
             let usuario = self._get_usuario(caller)?;
             usuario.es_vendedor()?;
+            self.validar_no_suspendido(caller)?;
+
+            //Validacion de categoria
+            self.buscar_categoria(id_categoria)?;
+
+            //Calcula el index de la nueva publicacion
+            let index_pub = self.cantidad_publicaciones;
+            self.cantidad_publicaciones = index_pub
+                .checked_add(1)
+                .ok_or(ErrorSistema::OverflowPublicaciones)?;
 
             //Crea la publicacion
-            let publicacion = Publicacion::new(
-                self.publicaciones.len() as u64,
+            let mut publicacion = Publicacion::new(
+                u64::from(index_pub),
                 nombre_producto,
                 descripcion,
                 precio,
-                categoria,
+                id_categoria,
                 stock,
                 usuario.account_id,
             );
+            publicacion.bloque_creacion = self.env().block_number();
 
             //Agrega la publicacion al sistema
-            self.publicaciones.push(publicacion.clone());
+            self.publicaciones.insert(index_pub, &publicacion);
+            self.actualizar_publicaciones_activas(id_categoria, true);
             //Agrega el index de la publicacion al vector personal del vendedor
             let mut publicaciones_vendedor = self
                 .publicaciones_mapping
                 .get(usuario.account_id)
                 .unwrap_or_default();
-
-            let index_pub = (self.publicaciones.len() as u32)
-                .checked_sub(1)
-                .ok_or(ErrorSistema::UnderflowPublicaciones)?; // Calcula el index
             publicaciones_vendedor.push(index_pub); // Agrega el index de la publicacion
 
             //Almacena el vector de indexs del usuario
             self.publicaciones_mapping
                 .insert(usuario.account_id, &publicaciones_vendedor);
 
+            //Agrega el index de la publicacion al indice de su categoria
+            let mut publicaciones_categoria = self
+                .publicaciones_categoria
+                .get(id_categoria)
+                .unwrap_or_default();
+            publicaciones_categoria.push(index_pub);
+            self.publicaciones_categoria
+                .insert(id_categoria, &publicaciones_categoria);
+
+            self.env().emit_event(PublicacionCreada {
+                vendedor_id: usuario.account_id,
+                idx_publicacion: index_pub,
+                nombre_producto: publicacion.nombre_producto.clone(),
+                precio: publicacion.precio,
+                id_categoria,
+                stock: publicacion.stock,
+            });
+
             Ok(publicacion)
         }
 
         //Retorna las publicaciones del vendedor solicitante
         #[ink(message)]
-        #[ignore]
         pub fn get_publicaciones_vendedor(&self) -> Result<Vec<Publicacion>, ErrorSistema> {
-            self._get_publicaciones_vendedor(self.env().caller())
+            let caller = self.env().caller();
+            self._get_publicaciones_vendedor(caller, caller)
         }
 
-        //Funcion prueba get_publicaciones_vendedor()
+        //Retorna las publicaciones activas de cualquier vendedor
+        #[ink(message)]
+        pub fn get_publicaciones_de_vendedor(
+            &self,
+            vendedor_id: AccountId,
+        ) -> Result<Vec<Publicacion>, ErrorSistema> {
+            self._get_publicaciones_vendedor(self.env().caller(), vendedor_id)
+        }
+
+        //Funcion prueba get_publicaciones_vendedor(). Las publicaciones pausadas
+        //solo las ve su propio vendedor
         fn _get_publicaciones_vendedor(
             &self,
             caller: AccountId,
+            vendedor_id: AccountId,
         ) -> Result<Vec<Publicacion>, ErrorSistema> {
             //Validacion de usuario
-            let usuario = self._get_usuario(caller)?;
-            usuario.es_vendedor()?;
+            self._get_usuario(caller)?;
+
+            //Validacion del vendedor consultado
+            let vendedor = self
+                .usuarios
+                .get(vendedor_id)
+                .ok_or(ErrorSistema::VendedorNoExistente)?;
+            vendedor.es_vendedor()?;
 
             //Obtiene el vector con ids de publicaciones del vendedor
             let ids_publicaciones_vendedor = self
                 .publicaciones_mapping
-                .get(usuario.account_id)
+                .get(vendedor.account_id)
                 .unwrap_or_default();
 
             //Recorre las publicaciones del sistema y arma un vector con las
-            //publicaciones del vendedor solicitante
+            //publicaciones del vendedor consultado
             let publicaciones_vendedor = ids_publicaciones_vendedor
                 .iter()
-                .filter_map(|&i| self.publicaciones.get(i as usize))
-                .cloned()
+                .filter_map(|&i| self.publicaciones.get(i))
+                .filter(|publicacion| caller == vendedor_id || publicacion.activa)
                 .collect();
 
             Ok(publicaciones_vendedor)
         }
 
+        //Retorna una pagina de las publicaciones del vendedor solicitante
+        #[ink(message)]
+        pub fn get_publicaciones_vendedor_paginado(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<Publicacion>, ErrorSistema> {
+            self._get_publicaciones_vendedor_paginado(self.env().caller(), offset, limit)
+        }
+
+        //Funcion prueba get_publicaciones_vendedor_paginado()
+        fn _get_publicaciones_vendedor_paginado(
+            &self,
+            caller: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<Publicacion>, ErrorSistema> {
+            //Validacion de usuario
+            let usuario = self._get_usuario(caller)?;
+            usuario.es_vendedor()?;
+
+            let ids_publicaciones_vendedor = self
+                .publicaciones_mapping
+                .get(usuario.account_id)
+                .unwrap_or_default();
+
+            let total = ids_publicaciones_vendedor.len() as u32;
+            let (inicio, fin) = rango_pagina(total, offset, limit);
+            let elementos = ids_publicaciones_vendedor[inicio as usize..fin as usize]
+                .iter()
+                .filter_map(|&i| self.publicaciones.get(i))
+                .collect();
+
+            Ok(Pagina { elementos, total })
+        }
+
         //Retorna las publicaciones de todos los vendedores
         #[ink(message)]
-        #[ignore]
         pub fn get_publicaciones(&self) -> Result<Vec<Publicacion>, ErrorSistema> {
             self._get_publicaciones(self.env().caller())
         }
@@ -270,78 +913,432 @@ mod marketplace {
         //Funcion prueba get_publicaciones()
         fn _get_publicaciones(&self, caller: AccountId) -> Result<Vec<Publicacion>, ErrorSistema> {
             self._get_usuario(caller)?;
-            Ok(self.publicaciones.clone())
+            Ok((0..self.cantidad_publicaciones)
+                .filter_map(|i| self.publicaciones.get(i))
+                .filter(|publicacion| publicacion.activa)
+                .collect())
         }
 
-        //Crea una orden de compra
+        //Busca publicaciones activas por categoria, rango de precio, stock y
+        //vendedor, opcionalmente ordenadas. Retorna una pagina de los resultados.
+        //Sin orden la busqueda se corta al llenar la pagina y `total` cuenta los
+        //resultados hasta ahi, mas uno si queda alguno despues, para poder pedir
+        //la siguiente. Con orden hay que cargar todos los candidatos para
+        //ordenarlos y `total` es la cantidad exacta de resultados
         #[ink(message)]
-        #[ignore]
-        pub fn ordenar_compra(
-            &mut self,
-            idx_publicacion: u32,
-        ) -> Result<OrdenCompra, ErrorSistema> {
-            self._ordenar_compra(self.env().caller(), idx_publicacion)
+        pub fn buscar_publicaciones(
+            &self,
+            filtro: FiltroPublicaciones,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<Publicacion>, ErrorSistema> {
+            self._buscar_publicaciones(self.env().caller(), filtro, offset, limit)
         }
 
-        //Funcion prueba ordenar_compra()
-        fn _ordenar_compra(
-            &mut self,
+        //Funcion prueba buscar_publicaciones()
+        fn _buscar_publicaciones(
+            &self,
             caller: AccountId,
-            idx_publicacion: u32,
-        ) -> Result<OrdenCompra, ErrorSistema> {
-            // validaciones de usuario
-            let usuario = self._get_usuario(caller)?;
-            usuario.es_comprador()?;
+            filtro: FiltroPublicaciones,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<Publicacion>, ErrorSistema> {
+            self._get_usuario(caller)?;
 
-            //Buscar publicacion
-            let mut publicacion = self
-                .publicaciones
-                .get(idx_publicacion as usize)
-                .cloned()
-                .ok_or(ErrorSistema::PublicacionNoExistente)?;
+            //La categoria filtrada junto con todas sus subcategorias
+            let categorias: Option<Vec<u32>> = filtro
+                .id_categoria
+                .map(|id_categoria| self.ids_categoria_y_subcategorias(id_categoria));
+
+            //Usa el indice mas chico disponible para no cargar todo el catalogo
+            let ids_candidatos: Vec<u32> = if let Some(vendedor_id) = filtro.vendedor_id {
+                self.publicaciones_mapping
+                    .get(vendedor_id)
+                    .unwrap_or_default()
+            } else if let Some(categorias) = &categorias {
+                let mut ids: Vec<u32> = categorias
+                    .iter()
+                    .flat_map(|&i| self.publicaciones_categoria.get(i).unwrap_or_default())
+                    .collect();
+                //Mantiene el orden de creacion entre categorias distintas
+                ids.sort_unstable();
+                ids
+            } else {
+                (0..self.cantidad_publicaciones).collect()
+            };
 
-            //Decrementar Stock
-            publicacion.stock = publicacion
-                .stock
-                .checked_sub(1)
-                .ok_or(ErrorSistema::PublicacionSinStock)?;
+            let mut resultados = ids_candidatos
+                .iter()
+                .filter_map(|&i| self.publicaciones.get(i))
+                .filter(|publicacion| {
+                    publicacion.activa
+                        && filtro.acepta(publicacion)
+                        && categorias
+                            .iter()
+                            .all(|categorias| categorias.contains(&publicacion.id_categoria))
+                });
+
+            if filtro.ordenar_por.is_none() {
+                //Lee los resultados en orden de creacion hasta el fin de la pagina
+                let (inicio, fin) = rango_pagina(u32::MAX, offset, limit);
+                let mut leidos: u32 = 0;
+                let mut elementos = Vec::new();
+                for publicacion in resultados.by_ref().take(fin as usize) {
+                    if leidos >= inicio {
+                        elementos.push(publicacion);
+                    }
+                    leidos = leidos.saturating_add(1);
+                }
+                //Un resultado mas alcanza para saber si hay otra pagina
+                let total = leidos.saturating_add(u32::from(resultados.next().is_some()));
 
-            // Reemplazar la publicación modificada
+                return Ok(Pagina { elementos, total });
+            }
 
-            // Reemplazar la publicación modificada
-            self.publicaciones[idx_publicacion as usize] = publicacion.clone();
+            //Para ordenar hay que cargar todos los candidatos: sin filtro de
+            //vendedor ni categoria es el catalogo entero, asi que el costo crece
+            //con la cantidad de publicaciones
+            let mut publicaciones: Vec<Publicacion> = resultados.collect();
+            if let Some(orden) = &filtro.ordenar_por {
+                orden.ordenar(&mut publicaciones);
+            }
 
-            // crear orden de compra
-            let orden_compra = OrdenCompra {
-                estado: Estado::Pendiente,
-                publicacion: publicacion.clone(),
-                comprador_id: usuario.account_id,
-                peticion_cancelacion: false,
-            };
+            //`total` es la cantidad de resultados que cumplen el filtro
+            let total = publicaciones.len() as u32;
+            let (inicio, fin) = rango_pagina(total, offset, limit);
+            let elementos = publicaciones.drain(inicio as usize..fin as usize).collect();
 
-            //Agrega la orden de compra al sistema
-            self.ordenes_compra.push(orden_compra.clone());
-            //Agrega el index de la orden de compra al vector personal del comprador
-            let mut ordenes_compra_comprador = self
-                .ordenes_compra_mapping
-                .get(usuario.account_id)
-                .unwrap_or_default();
+            Ok(Pagina { elementos, total })
+        }
+
+        //Retorna una pagina de las publicaciones de todos los vendedores
+        #[ink(message)]
+        pub fn get_publicaciones_paginado(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<Publicacion>, ErrorSistema> {
+            self._get_publicaciones_paginado(self.env().caller(), offset, limit)
+        }
+
+        //Funcion prueba get_publicaciones_paginado()
+        fn _get_publicaciones_paginado(
+            &self,
+            caller: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<Publicacion>, ErrorSistema> {
+            self._get_usuario(caller)?;
+
+            //Las publicaciones pausadas se omiten, por lo que una pagina puede
+            //traer menos elementos que `limit`. `total` es la cantidad de ids del
+            //catalogo, incluidas las pausadas: se pagina por offset hasta llegar a el
+            let total = self.cantidad_publicaciones;
+            let (inicio, fin) = rango_pagina(total, offset, limit);
+            let elementos = (inicio..fin)
+                .filter_map(|i| self.publicaciones.get(i))
+                .filter(|publicacion| publicacion.activa)
+                .collect();
+
+            Ok(Pagina { elementos, total })
+        }
+
+        //Edita los datos de una publicacion, solo se modifican los campos con valor
+        #[ink(message)]
+        pub fn editar_publicacion(
+            &mut self,
+            idx_publicacion: u32,
+            nombre_producto: Option<String>,
+            descripcion: Option<String>,
+            precio: Option<u64>,
+            stock: Option<u64>,
+        ) -> Result<Publicacion, ErrorSistema> {
+            self._editar_publicacion(
+                self.env().caller(),
+                idx_publicacion,
+                nombre_producto,
+                descripcion,
+                precio,
+                stock,
+            )
+        }
+
+        //Funcion prueba editar_publicacion()
+        fn _editar_publicacion(
+            &mut self,
+            caller: AccountId,
+            idx_publicacion: u32,
+            nombre_producto: Option<String>,
+            descripcion: Option<String>,
+            precio: Option<u64>,
+            stock: Option<u64>,
+        ) -> Result<Publicacion, ErrorSistema> {
+            let mut publicacion = self.buscar_publicacion_del_vendedor(caller, idx_publicacion)?;
+
+            if let Some(nombre_producto) = nombre_producto {
+                publicacion.nombre_producto = nombre_producto;
+            }
+            if let Some(descripcion) = descripcion {
+                publicacion.descripcion = descripcion;
+            }
+            if let Some(precio) = precio {
+                publicacion.precio = precio;
+            }
+            if let Some(stock) = stock {
+                publicacion.stock = stock;
+            }
+
+            self.publicaciones.insert(idx_publicacion, &publicacion);
+            self.emitir_publicacion_actualizada(idx_publicacion, &publicacion);
+
+            Ok(publicacion)
+        }
+
+        //Suma unidades al stock de una publicacion
+        #[ink(message)]
+        pub fn reponer_stock(
+            &mut self,
+            idx_publicacion: u32,
+            cantidad: u64,
+        ) -> Result<Publicacion, ErrorSistema> {
+            self._reponer_stock(self.env().caller(), idx_publicacion, cantidad)
+        }
+
+        //Funcion prueba reponer_stock()
+        fn _reponer_stock(
+            &mut self,
+            caller: AccountId,
+            idx_publicacion: u32,
+            cantidad: u64,
+        ) -> Result<Publicacion, ErrorSistema> {
+            let mut publicacion = self.buscar_publicacion_del_vendedor(caller, idx_publicacion)?;
+
+            if cantidad == 0 {
+                return Err(ErrorSistema::CantidadInvalida);
+            }
+
+            publicacion.stock = publicacion
+                .stock
+                .checked_add(cantidad)
+                .ok_or(ErrorSistema::OverflowStock)?;
+
+            self.publicaciones.insert(idx_publicacion, &publicacion);
+            self.emitir_publicacion_actualizada(idx_publicacion, &publicacion);
+
+            Ok(publicacion)
+        }
+
+        //Pausa una publicacion, deja de listarse y de aceptar ordenes
+        #[ink(message)]
+        pub fn pausar_publicacion(
+            &mut self,
+            idx_publicacion: u32,
+        ) -> Result<Publicacion, ErrorSistema> {
+            self._pausar_publicacion(self.env().caller(), idx_publicacion)
+        }
+
+        //Funcion prueba pausar_publicacion()
+        fn _pausar_publicacion(
+            &mut self,
+            caller: AccountId,
+            idx_publicacion: u32,
+        ) -> Result<Publicacion, ErrorSistema> {
+            let mut publicacion = self.buscar_publicacion_del_vendedor(caller, idx_publicacion)?;
+
+            if !publicacion.activa {
+                return Err(ErrorSistema::PublicacionPausada);
+            }
+            publicacion.activa = false;
+
+            self.publicaciones.insert(idx_publicacion, &publicacion);
+            self.actualizar_publicaciones_activas(publicacion.id_categoria, false);
+            self.emitir_publicacion_actualizada(idx_publicacion, &publicacion);
+
+            Ok(publicacion)
+        }
+
+        //Reanuda una publicacion pausada
+        #[ink(message)]
+        pub fn reanudar_publicacion(
+            &mut self,
+            idx_publicacion: u32,
+        ) -> Result<Publicacion, ErrorSistema> {
+            self._reanudar_publicacion(self.env().caller(), idx_publicacion)
+        }
+
+        //Funcion prueba reanudar_publicacion()
+        fn _reanudar_publicacion(
+            &mut self,
+            caller: AccountId,
+            idx_publicacion: u32,
+        ) -> Result<Publicacion, ErrorSistema> {
+            let mut publicacion = self.buscar_publicacion_del_vendedor(caller, idx_publicacion)?;
+
+            if publicacion.dada_de_baja {
+                return Err(ErrorSistema::PublicacionDadaDeBaja);
+            }
+            if publicacion.activa {
+                return Err(ErrorSistema::PublicacionYaActiva);
+            }
+            publicacion.activa = true;
+
+            self.publicaciones.insert(idx_publicacion, &publicacion);
+            self.actualizar_publicaciones_activas(publicacion.id_categoria, true);
+            self.emitir_publicacion_actualizada(idx_publicacion, &publicacion);
+
+            Ok(publicacion)
+        }
+
+        //Crea una orden de compra, el pago queda retenido en el contrato
+        //hasta que la orden se recibe o se cancela
+        #[ink(message, payable)]
+        pub fn ordenar_compra(
+            &mut self,
+            idx_publicacion: u32,
+            cantidad: u64,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            self._ordenar_compra(
+                self.env().caller(),
+                idx_publicacion,
+                cantidad,
+                self.env().transferred_value(),
+            )
+        }
+
+        //Funcion prueba ordenar_compra()
+        fn _ordenar_compra(
+            &mut self,
+            caller: AccountId,
+            idx_publicacion: u32,
+            cantidad: u64,
+            valor_transferido: Balance,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            // validaciones de usuario
+            let usuario = self._get_usuario(caller)?;
+            usuario.es_comprador()?;
+            self.validar_no_suspendido(caller)?;
+
+            //Buscar publicacion
+            let mut publicacion = self.buscar_publicacion(idx_publicacion)?;
+
+            if publicacion.dada_de_baja {
+                return Err(ErrorSistema::PublicacionDadaDeBaja);
+            }
+            if !publicacion.activa {
+                return Err(ErrorSistema::PublicacionPausada);
+            }
+
+            if cantidad == 0 {
+                return Err(ErrorSistema::CantidadInvalida);
+            }
+
+            //Calcular el precio total de la compra
+            let precio_total = publicacion
+                .precio
+                .checked_mul(cantidad)
+                .ok_or(ErrorSistema::OverflowPrecioTotal)?;
+
+            //Validar que el pago coincida con el precio total
+            if valor_transferido != Balance::from(precio_total) {
+                return Err(ErrorSistema::MontoIncorrecto);
+            }
+
+            //Decrementar Stock
+            publicacion.stock = publicacion
+                .stock
+                .checked_sub(cantidad)
+                .ok_or(ErrorSistema::PublicacionSinStock)?;
+            publicacion.unidades_vendidas = publicacion.unidades_vendidas.saturating_add(cantidad);
+
+            // Reemplazar la publicación modificada
+            self.publicaciones.insert(idx_publicacion, &publicacion);
+
+            // crear orden de compra
+            let orden_compra = OrdenCompra {
+                estado: Estado::Pendiente,
+                id_publicacion: idx_publicacion,
+                vendedor_id: publicacion.vendedor_id,
+                comprador_id: usuario.account_id,
+                nombre_producto: publicacion.nombre_producto.clone(),
+                precio_unitario: publicacion.precio,
+                cantidad,
+                peticion_cancelacion: false,
+                cancelacion_solicitada: false,
+                calificada_por_comprador: false,
+                calificada_por_vendedor: false,
+            };
 
-            let index_ord = (self.ordenes_compra.len() as u32)
-                .checked_sub(1)
-                .ok_or(ErrorSistema::UnderflowOrdenes)?; // Calcula el index
+            //Calcula el index de la nueva orden de compra
+            let index_ord = self.cantidad_ordenes;
+            self.cantidad_ordenes = index_ord
+                .checked_add(1)
+                .ok_or(ErrorSistema::OverflowOrdenes)?;
+            let posicion_vendedor = self
+                .cantidad_ordenes_vendedor
+                .get(publicacion.vendedor_id)
+                .unwrap_or(0);
+            let cantidad_ordenes_vendedor = posicion_vendedor
+                .checked_add(1)
+                .ok_or(ErrorSistema::OverflowOrdenes)?;
+            let posicion_publicacion = self
+                .cantidad_ordenes_publicacion
+                .get(idx_publicacion)
+                .unwrap_or(0);
+            let cantidad_ordenes_publicacion = posicion_publicacion
+                .checked_add(1)
+                .ok_or(ErrorSistema::OverflowOrdenes)?;
+
+            //Agrega la orden de compra al sistema
+            self.ordenes_compra.insert(index_ord, &orden_compra);
+            //Agrega el index de la orden de compra al vector personal del comprador
+            let mut ordenes_compra_comprador = self
+                .ordenes_compra_mapping
+                .get(usuario.account_id)
+                .unwrap_or_default();
             ordenes_compra_comprador.push(index_ord); // Agrega el index de la orden de compra
 
             //Almacena el vector de indexs del usuario
             self.ordenes_compra_mapping
                 .insert(usuario.account_id, &ordenes_compra_comprador);
 
+            //Agrega el index de la orden de compra al indice del vendedor de la publicacion
+            self.ordenes_vendedor_mapping
+                .insert((publicacion.vendedor_id, posicion_vendedor), &index_ord);
+            self.cantidad_ordenes_vendedor
+                .insert(publicacion.vendedor_id, &cantidad_ordenes_vendedor);
+            //Y al indice de la publicacion, que usa la moderacion al darla de baja
+            self.ordenes_publicacion
+                .insert((idx_publicacion, posicion_publicacion), &index_ord);
+            self.cantidad_ordenes_publicacion
+                .insert(idx_publicacion, &cantidad_ordenes_publicacion);
+
+            //Retiene el pago hasta que se resuelva la orden
+            self.fondos_retenidos.insert(index_ord, &valor_transferido);
+            self.actualizar_contadores_usuario(publicacion.vendedor_id, |contadores| {
+                contadores.ventas = contadores.ventas.saturating_add(1)
+            });
+            self.actualizar_contadores_usuario(usuario.account_id, |contadores| {
+                contadores.compras = contadores.compras.saturating_add(1);
+                contadores.total_gastado = contadores
+                    .total_gastado
+                    .saturating_add(Balance::from(precio_total));
+            });
+
+            self.emitir_publicacion_actualizada(idx_publicacion, &publicacion);
+            self.env().emit_event(OrdenCreada {
+                comprador_id: usuario.account_id,
+                vendedor_id: publicacion.vendedor_id,
+                idx_orden: index_ord,
+                idx_publicacion,
+                cantidad,
+                monto: valor_transferido,
+            });
+
             Ok(orden_compra)
         }
 
         //Retorna las ordenes de compra del comprador solicitante
         #[ink(message)]
-        #[ignore]
         pub fn get_ordenes_comprador(&self) -> Result<Vec<OrdenCompra>, ErrorSistema> {
             self._get_ordenes_comprador(self.env().caller())
         }
@@ -365,16 +1362,81 @@ mod marketplace {
             //ordenes de compra del comprador solicitante
             let ordenes_compra_comprador = ids_ordenes_compra_comprador
                 .iter()
-                .filter_map(|&i| self.ordenes_compra.get(i as usize))
-                .cloned()
+                .filter_map(|&i| self.ordenes_compra.get(i))
                 .collect();
 
             Ok(ordenes_compra_comprador)
         }
 
+        //Retorna una pagina de las ordenes de compra del comprador solicitante
+        #[ink(message)]
+        pub fn get_ordenes_comprador_paginado(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<OrdenCompra>, ErrorSistema> {
+            self._get_ordenes_comprador_paginado(self.env().caller(), offset, limit)
+        }
+
+        //Funcion prueba get_ordenes_comprador_paginado()
+        fn _get_ordenes_comprador_paginado(
+            &self,
+            caller: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<OrdenCompra>, ErrorSistema> {
+            //Validacion de usuario
+            let usuario = self._get_usuario(caller)?;
+            usuario.es_comprador()?;
+
+            let ids_ordenes_compra_comprador = self
+                .ordenes_compra_mapping
+                .get(usuario.account_id)
+                .unwrap_or_default();
+
+            let total = ids_ordenes_compra_comprador.len() as u32;
+            let (inicio, fin) = rango_pagina(total, offset, limit);
+            let elementos = ids_ordenes_compra_comprador[inicio as usize..fin as usize]
+                .iter()
+                .filter_map(|&i| self.ordenes_compra.get(i))
+                .collect();
+
+            Ok(Pagina { elementos, total })
+        }
+
+        //Retorna las ordenes de compra recibidas por las publicaciones del vendedor
+        //solicitante, opcionalmente filtradas por estado
+        #[ink(message)]
+        pub fn get_ordenes_vendedor(
+            &self,
+            estado: Option<Estado>,
+        ) -> Result<Vec<OrdenCompra>, ErrorSistema> {
+            self._get_ordenes_vendedor(self.env().caller(), estado)
+        }
+
+        //Funcion prueba get_ordenes_vendedor()
+        fn _get_ordenes_vendedor(
+            &self,
+            caller: AccountId,
+            estado: Option<Estado>,
+        ) -> Result<Vec<OrdenCompra>, ErrorSistema> {
+            //Validacion de usuario
+            let usuario = self._get_usuario(caller)?;
+            usuario.es_vendedor()?;
+
+            //Obtiene los ids de ordenes de compra del vendedor
+            let ordenes_vendedor = self
+                .ids_ordenes_vendedor(usuario.account_id)
+                .into_iter()
+                .filter_map(|i| self.ordenes_compra.get(i))
+                .filter(|orden| estado.is_none() || estado.as_ref() == Some(&orden.estado))
+                .collect();
+
+            Ok(ordenes_vendedor)
+        }
+
         //Retorna las ordenes de compra de todos los compradores
         #[ink(message)]
-        #[ignore]
         pub fn get_ordenes(&self) -> Result<Vec<OrdenCompra>, ErrorSistema> {
             self._get_ordenes(self.env().caller())
         }
@@ -382,734 +1444,5621 @@ mod marketplace {
         //Funcion prueba get_ordenes
         fn _get_ordenes(&self, caller: AccountId) -> Result<Vec<OrdenCompra>, ErrorSistema> {
             self._get_usuario(caller)?;
-            Ok(self.ordenes_compra.clone())
+            Ok((0..self.cantidad_ordenes)
+                .filter_map(|i| self.ordenes_compra.get(i))
+                .collect())
         }
-    }
 
-    impl Publicacion {
-        pub fn new(
-            id_publicacion: u64,
-            nombre_producto: String,
-            descripcion: String,
-            precio: u64,
-            categoria: Categoria,
-            stock: u64,
-            vendedor_id: AccountId,
-        ) -> Publicacion {
-            Publicacion {
-                id_publicacion,
-                nombre_producto,
-                descripcion,
-                precio,
-                categoria,
-                stock,
-                vendedor_id,
-            }
+        //Retorna una pagina de las ordenes de compra de todos los compradores
+        #[ink(message)]
+        pub fn get_ordenes_paginado(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<OrdenCompra>, ErrorSistema> {
+            self._get_ordenes_paginado(self.env().caller(), offset, limit)
         }
-    }
 
-    impl Usuario {
-        //Valida que el usuario tenga rol Vendedor o Ambos
-        fn es_vendedor(&self) -> Result<bool, ErrorSistema> {
-            if matches!(self.rol, Rol::Comprador) {
-                Err(ErrorSistema::UsuarioNoEsVendedor)
-            } else {
-                Ok(true)
-            }
+        //Funcion prueba get_ordenes_paginado()
+        fn _get_ordenes_paginado(
+            &self,
+            caller: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Pagina<OrdenCompra>, ErrorSistema> {
+            self._get_usuario(caller)?;
+            Ok(self.get_ordenes_reporte(offset, limit))
         }
 
-        //Valida que el usuario tenga rol Comprador o Ambos
-        fn es_comprador(&self) -> Result<bool, ErrorSistema> {
-            if matches!(self.rol, Rol::Vendedor) {
-                Err(ErrorSistema::UsuarioNoEsComprador)
-            } else {
-                Ok(true)
-            }
+        //Retorna el owner del contrato
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+        //Indica si la cuenta es el owner o un admin
+        #[ink(message)]
+        pub fn es_admin(&self, account_id: AccountId) -> bool {
+            self.solo_admin(account_id).is_ok()
+        }
 
-        mod tests_es_vendedor {
-            use super::*;
+        //Da permisos de administracion a una cuenta. Solo el owner
+        #[ink(message)]
+        pub fn agregar_admin(&mut self, account_id: AccountId) -> Result<(), ErrorSistema> {
+            self._agregar_admin(self.env().caller(), account_id)
+        }
 
-            #[test]
-            fn tests_es_vendedor_true_vendedor() {
-                let usuario = Usuario {
-                    account_id: AccountId::from([0xAA; 32]),
-                    username: "agustin22".to_string(),
-                    rol: Rol::Vendedor,
-                };
+        //Funcion prueba agregar_admin()
+        fn _agregar_admin(
+            &mut self,
+            caller: AccountId,
+            account_id: AccountId,
+        ) -> Result<(), ErrorSistema> {
+            self.solo_owner(caller)?;
 
-                assert_eq!(usuario.es_vendedor().is_ok(), true);
+            if !self.admins.contains(account_id) {
+                self.admins.insert(account_id, &());
+                self.env().emit_event(AdminAgregado { account_id });
             }
 
-            #[test]
-            fn tests_es_vendedor_true_ambos() {
-                let usuario = Usuario {
-                    account_id: AccountId::from([0xAA; 32]),
-                    username: "agustin22".to_string(),
-                    rol: Rol::Ambos,
-                };
+            Ok(())
+        }
+
+        //Quita los permisos de administracion a una cuenta. Solo el owner
+        #[ink(message)]
+        pub fn quitar_admin(&mut self, account_id: AccountId) -> Result<(), ErrorSistema> {
+            self._quitar_admin(self.env().caller(), account_id)
+        }
 
-                assert_eq!(usuario.es_vendedor().is_ok(), true);
+        //Funcion prueba quitar_admin()
+        fn _quitar_admin(
+            &mut self,
+            caller: AccountId,
+            account_id: AccountId,
+        ) -> Result<(), ErrorSistema> {
+            self.solo_owner(caller)?;
+
+            if self.admins.take(account_id).is_some() {
+                self.env().emit_event(AdminQuitado { account_id });
             }
 
-            #[test]
+            Ok(())
+        }
+
+        //Propone un nuevo owner. La transferencia se completa cuando el nuevo owner
+        //la acepta con aceptar_ownership. Solo el owner
+        #[ink(message)]
+        pub fn transferir_ownership(&mut self, nuevo_owner: AccountId) -> Result<(), ErrorSistema> {
+            self._transferir_ownership(self.env().caller(), nuevo_owner)
+        }
+
+        //Funcion prueba transferir_ownership()
+        fn _transferir_ownership(
+            &mut self,
+            caller: AccountId,
+            nuevo_owner: AccountId,
+        ) -> Result<(), ErrorSistema> {
+            self.solo_owner(caller)?;
+            self.owner_pendiente = Some(nuevo_owner);
+
+            self.env().emit_event(OwnershipPropuesta {
+                owner: caller,
+                nuevo_owner,
+            });
+
+            Ok(())
+        }
+
+        //El owner propuesto acepta la transferencia
+        #[ink(message)]
+        pub fn aceptar_ownership(&mut self) -> Result<(), ErrorSistema> {
+            self._aceptar_ownership(self.env().caller())
+        }
+
+        //Funcion prueba aceptar_ownership()
+        fn _aceptar_ownership(&mut self, caller: AccountId) -> Result<(), ErrorSistema> {
+            if self.owner_pendiente != Some(caller) {
+                return Err(ErrorSistema::NoAutorizado);
+            }
+
+            let owner_anterior = self.owner;
+            self.owner = caller;
+            self.owner_pendiente = None;
+
+            self.env().emit_event(OwnershipTransferido {
+                owner_anterior,
+                owner_nuevo: caller,
+            });
+
+            Ok(())
+        }
+
+        //Suspende a un usuario: no puede publicar ni ordenar compras hasta
+        //`hasta_bloque`, o hasta que se levante la suspension si es None. Solo admins
+        #[ink(message)]
+        pub fn suspender_usuario(
+            &mut self,
+            account_id: AccountId,
+            motivo: String,
+            hasta_bloque: Option<BlockNumber>,
+        ) -> Result<Suspension, ErrorSistema> {
+            self._suspender_usuario(self.env().caller(), account_id, motivo, hasta_bloque)
+        }
+
+        //Funcion prueba suspender_usuario()
+        fn _suspender_usuario(
+            &mut self,
+            caller: AccountId,
+            account_id: AccountId,
+            motivo: String,
+            hasta_bloque: Option<BlockNumber>,
+        ) -> Result<Suspension, ErrorSistema> {
+            self.solo_admin(caller)?;
+            self._get_usuario(account_id)?;
+
+            let suspension = Suspension {
+                motivo,
+                hasta_bloque,
+            };
+            self.suspensiones.insert(account_id, &suspension);
+
+            self.env().emit_event(UsuarioSuspendido {
+                account_id,
+                motivo: suspension.motivo.clone(),
+                hasta_bloque,
+            });
+
+            Ok(suspension)
+        }
+
+        //Levanta la suspension de un usuario. Solo admins
+        #[ink(message)]
+        pub fn levantar_suspension(&mut self, account_id: AccountId) -> Result<(), ErrorSistema> {
+            self._levantar_suspension(self.env().caller(), account_id)
+        }
+
+        //Funcion prueba levantar_suspension()
+        fn _levantar_suspension(
+            &mut self,
+            caller: AccountId,
+            account_id: AccountId,
+        ) -> Result<(), ErrorSistema> {
+            self.solo_admin(caller)?;
+
+            if self.suspensiones.take(account_id).is_some() {
+                self.env().emit_event(SuspensionLevantada { account_id });
+            }
+
+            Ok(())
+        }
+
+        //Retorna la suspension vigente de un usuario, si tiene
+        #[ink(message)]
+        pub fn get_suspension(&self, account_id: AccountId) -> Option<Suspension> {
+            self.suspensiones
+                .get(account_id)
+                .filter(|suspension| match suspension.hasta_bloque {
+                    Some(hasta_bloque) => self.env().block_number() < hasta_bloque,
+                    None => true,
+                })
+        }
+
+        //Da de baja una publicacion: se oculta, no acepta ordenes y sus ordenes
+        //pendientes se cancelan con reintegro al comprador. Solo admins
+        #[ink(message)]
+        pub fn dar_de_baja_publicacion(
+            &mut self,
+            idx_publicacion: u32,
+            motivo: String,
+        ) -> Result<Publicacion, ErrorSistema> {
+            self._dar_de_baja_publicacion(self.env().caller(), idx_publicacion, motivo)
+        }
+
+        //Funcion prueba dar_de_baja_publicacion()
+        fn _dar_de_baja_publicacion(
+            &mut self,
+            caller: AccountId,
+            idx_publicacion: u32,
+            motivo: String,
+        ) -> Result<Publicacion, ErrorSistema> {
+            self.solo_admin(caller)?;
+
+            let mut publicacion = self.buscar_publicacion(idx_publicacion)?;
+            if publicacion.dada_de_baja {
+                return Err(ErrorSistema::PublicacionDadaDeBaja);
+            }
+            //Si estaba pausada ya no se contaba como activa
+            if publicacion.activa {
+                self.actualizar_publicaciones_activas(publicacion.id_categoria, false);
+            }
+            publicacion.activa = false;
+            publicacion.dada_de_baja = true;
+            self.publicaciones.insert(idx_publicacion, &publicacion);
+
+            //Cancela las ordenes pendientes de la publicacion
+            let cantidad_ordenes = self
+                .cantidad_ordenes_publicacion
+                .get(idx_publicacion)
+                .unwrap_or(0);
+            let ids_ordenes_pendientes: Vec<u32> = (0..cantidad_ordenes)
+                .filter_map(|posicion| self.ordenes_publicacion.get((idx_publicacion, posicion)))
+                .filter(|&i| {
+                    self.ordenes_compra
+                        .get(i)
+                        .is_some_and(|orden| orden.estado == Estado::Pendiente)
+                })
+                .collect();
+            for &idx_orden in ids_ordenes_pendientes.iter() {
+                let mut orden_compra = self.buscar_orden(idx_orden)?;
+                self.cancelar_orden(idx_orden, &mut orden_compra)?;
+            }
+
+            self.env().emit_event(PublicacionDadaDeBaja {
+                vendedor_id: publicacion.vendedor_id,
+                idx_publicacion,
+                motivo,
+                ordenes_canceladas: ids_ordenes_pendientes.len() as u32,
+            });
+
+            //Se vuelve a leer porque la cancelacion de las ordenes repone el stock
+            self.buscar_publicacion(idx_publicacion)
+        }
+
+        //Crea una categoria, o una subcategoria si se indica la categoria padre.
+        //Solo admins
+        #[ink(message)]
+        pub fn crear_categoria(
+            &mut self,
+            nombre: String,
+            id_padre: Option<u32>,
+        ) -> Result<Categoria, ErrorSistema> {
+            self._crear_categoria(self.env().caller(), nombre, id_padre)
+        }
+
+        //Funcion prueba crear_categoria()
+        fn _crear_categoria(
+            &mut self,
+            caller: AccountId,
+            nombre: String,
+            id_padre: Option<u32>,
+        ) -> Result<Categoria, ErrorSistema> {
+            self.solo_admin(caller)?;
+            self.agregar_categoria(nombre, id_padre)
+        }
+
+        //Cambia el nombre de una categoria. Solo admins
+        #[ink(message)]
+        pub fn renombrar_categoria(
+            &mut self,
+            id_categoria: u32,
+            nombre: String,
+        ) -> Result<Categoria, ErrorSistema> {
+            self._renombrar_categoria(self.env().caller(), id_categoria, nombre)
+        }
+
+        //Funcion prueba renombrar_categoria()
+        fn _renombrar_categoria(
+            &mut self,
+            caller: AccountId,
+            id_categoria: u32,
+            nombre: String,
+        ) -> Result<Categoria, ErrorSistema> {
+            self.solo_admin(caller)?;
+            validar_nombre_categoria(&nombre)?;
+
+            let mut categoria = self.buscar_categoria(id_categoria)?;
+            //Si es el mismo nombre que ya tiene no hay nada que reservar
+            if categoria.nombre != nombre {
+                if self.nombres_categorias.contains(&nombre) {
+                    return Err(ErrorSistema::NombreCategoriaEnUso);
+                }
+                self.nombres_categorias.remove(&categoria.nombre);
+                self.nombres_categorias.insert(&nombre, &id_categoria);
+            }
+            categoria.nombre = nombre;
+            self.categorias.insert(id_categoria, &categoria);
+
+            self.env().emit_event(CategoriaRenombrada {
+                id_categoria,
+                nombre: categoria.nombre.clone(),
+            });
+
+            Ok(categoria)
+        }
+
+        //Retorna todas las categorias, ordenadas por id
+        #[ink(message)]
+        pub fn get_categorias(&self) -> Vec<Categoria> {
+            (0..self.cantidad_categorias)
+                .filter_map(|i| self.categorias.get(i))
+                .collect()
+        }
+
+        //Busca un usuario por su username
+        #[ink(message)]
+        pub fn buscar_usuario_por_username(&self, username: String) -> Option<Usuario> {
+            self.usernames
+                .get(&username)
+                .and_then(|account_id| self.usuarios.get(account_id))
+        }
+
+        //Retorna los datos publicos de cualquier usuario sin requerir un usuario
+        //registrado. Pensado para contratos de reportes
+        #[ink(message)]
+        pub fn get_usuario_reporte(&self, account_id: AccountId) -> Option<Usuario> {
+            self.usuarios.get(account_id)
+        }
+
+        //Retorna una pagina de los usuarios registrados, en orden de registro, con
+        //los contadores de sus ordenes. No requiere un usuario registrado. Pensado
+        //para contratos de reportes
+        #[ink(message)]
+        pub fn get_resumenes_usuarios_reporte(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<ResumenUsuario> {
+            let total = self.cantidad_usuarios;
+            let (inicio, fin) = rango_pagina(total, offset, limit);
+            let elementos = (inicio..fin)
+                .filter_map(|i| self.usuarios_registrados.get(i))
+                .filter_map(|account_id| self.usuarios.get(account_id))
+                .map(|usuario| ResumenUsuario {
+                    account_id: usuario.account_id,
+                    username: usuario.username,
+                    contadores: self
+                        .contadores_usuarios
+                        .get(usuario.account_id)
+                        .unwrap_or_default(),
+                })
+                .collect();
+
+            Pagina { elementos, total }
+        }
+
+        //Retorna una pagina de las categorias, ordenadas por id, con sus
+        //publicaciones activas y ventas recibidas. No requiere un usuario
+        //registrado. Pensado para contratos de reportes
+        #[ink(message)]
+        pub fn get_ventas_categorias_reporte(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<VentasCategoria> {
+            let total = self.cantidad_categorias;
+            let (inicio, fin) = rango_pagina(total, offset, limit);
+            let elementos = (inicio..fin)
+                .filter_map(|i| self.categorias.get(i))
+                .map(|categoria| VentasCategoria {
+                    contadores: self
+                        .contadores_categorias
+                        .get(categoria.id_categoria)
+                        .unwrap_or_default(),
+                    categoria,
+                })
+                .collect();
+
+            Pagina { elementos, total }
+        }
+
+        //Retorna los totales de publicaciones, ordenes y ventas del Marketplace sin
+        //requerir un usuario registrado. Pensado para contratos de reportes
+        #[ink(message)]
+        pub fn get_resumen_ventas_reporte(&self) -> ResumenVentas {
+            ResumenVentas {
+                cantidad_publicaciones: self.cantidad_publicaciones,
+                cantidad_ordenes: self.cantidad_ordenes,
+                contadores: self.contadores_ventas.clone(),
+            }
+        }
+
+        //Retorna una pagina de todas las publicaciones, incluidas las pausadas, sin
+        //requerir un usuario registrado. Pensado para contratos de reportes
+        #[ink(message)]
+        pub fn get_publicaciones_reporte(&self, offset: u32, limit: u32) -> Pagina<Publicacion> {
+            let total = self.cantidad_publicaciones;
+            let (inicio, fin) = rango_pagina(total, offset, limit);
+            let elementos = (inicio..fin)
+                .filter_map(|i| self.publicaciones.get(i))
+                .collect();
+
+            Pagina { elementos, total }
+        }
+
+        //Retorna una pagina de todas las ordenes de compra sin requerir un usuario
+        //registrado. Pensado para contratos de reportes
+        #[ink(message)]
+        pub fn get_ordenes_reporte(&self, offset: u32, limit: u32) -> Pagina<OrdenCompra> {
+            let total = self.cantidad_ordenes;
+            let (inicio, fin) = rango_pagina(total, offset, limit);
+            let elementos = (inicio..fin)
+                .filter_map(|i| self.ordenes_compra.get(i))
+                .collect();
+
+            Pagina { elementos, total }
+        }
+
+        //Retorna una pagina de las ordenes de un vendedor, en orden de creacion, sin
+        //requerir un usuario registrado. Pensado para contratos de reportes
+        #[ink(message)]
+        pub fn get_ordenes_vendedor_reporte(
+            &self,
+            vendedor_id: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<OrdenCompra> {
+            let total = self.cantidad_ordenes_vendedor.get(vendedor_id).unwrap_or(0);
+            let (inicio, fin) = rango_pagina(total, offset, limit);
+            let elementos = (inicio..fin)
+                .filter_map(|posicion| self.ordenes_vendedor_mapping.get((vendedor_id, posicion)))
+                .filter_map(|i| self.ordenes_compra.get(i))
+                .collect();
+
+            Pagina { elementos, total }
+        }
+
+        //Retorna una orden de compra junto con la publicacion actual
+        #[ink(message)]
+        pub fn get_orden_detalle(&self, idx_orden: u32) -> Result<OrdenDetalle, ErrorSistema> {
+            self._get_orden_detalle(self.env().caller(), idx_orden)
+        }
+
+        //Funcion prueba get_orden_detalle()
+        fn _get_orden_detalle(
+            &self,
+            caller: AccountId,
+            idx_orden: u32,
+        ) -> Result<OrdenDetalle, ErrorSistema> {
+            self._get_usuario(caller)?;
+
+            let orden = self.buscar_orden(idx_orden)?;
+            let publicacion = self.buscar_publicacion(orden.id_publicacion)?;
+
+            Ok(OrdenDetalle { orden, publicacion })
+        }
+
+        //Marca una orden de compra como enviada
+        #[ink(message)]
+        pub fn marcar_enviada(&mut self, idx_orden: u32) -> Result<OrdenCompra, ErrorSistema> {
+            self._marcar_enviada(self.env().caller(), idx_orden)
+        }
+
+        //Funcion prueba marcar_enviada()
+        fn _marcar_enviada(
+            &mut self,
+            caller: AccountId,
+            idx_orden: u32,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            //Validacion de usuario
+            self._get_usuario(caller)?;
+
+            //Buscar orden de compra
+            let mut orden_compra = self.buscar_orden(idx_orden)?;
+
+            //Solo el vendedor de la publicacion puede marcarla como enviada
+            if orden_compra.vendedor_id != caller {
+                return Err(ErrorSistema::NoEsVendedorDeLaOrden);
+            }
+
+            //No se puede enviar mientras el comprador espera respuesta a su cancelacion
+            if orden_compra.peticion_cancelacion {
+                return Err(ErrorSistema::CancelacionPendiente);
+            }
+
+            let estado_anterior = orden_compra.estado.clone();
+            orden_compra.estado = orden_compra.estado.transicionar(Estado::Enviada)?;
+
+            //Reemplazar la orden de compra modificada
+            self.ordenes_compra.insert(idx_orden, &orden_compra);
+
+            self.env().emit_event(EstadoOrdenCambiado {
+                comprador_id: orden_compra.comprador_id,
+                vendedor_id: orden_compra.vendedor_id,
+                idx_orden,
+                estado_anterior,
+                estado_nuevo: orden_compra.estado.clone(),
+            });
+
+            Ok(orden_compra)
+        }
+
+        //Marca una orden de compra como recibida
+        #[ink(message)]
+        pub fn marcar_recibida(&mut self, idx_orden: u32) -> Result<OrdenCompra, ErrorSistema> {
+            self._marcar_recibida(self.env().caller(), idx_orden)
+        }
+
+        //Funcion prueba marcar_recibida()
+        fn _marcar_recibida(
+            &mut self,
+            caller: AccountId,
+            idx_orden: u32,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            //Validacion de usuario
+            self._get_usuario(caller)?;
+
+            //Buscar orden de compra
+            let mut orden_compra = self.buscar_orden(idx_orden)?;
+
+            //Solo el comprador puede marcarla como recibida
+            if orden_compra.comprador_id != caller {
+                return Err(ErrorSistema::NoEsCompradorDeLaOrden);
+            }
+
+            let estado_anterior = orden_compra.estado.clone();
+            orden_compra.estado = orden_compra.estado.transicionar(Estado::Recibida)?;
+
+            //Libera el pago retenido al vendedor
+            self.liberar_fondos(idx_orden, orden_compra.vendedor_id)?;
+
+            //Reemplazar la orden de compra modificada
+            self.ordenes_compra.insert(idx_orden, &orden_compra);
+            self.registrar_venta(&orden_compra);
+
+            self.env().emit_event(EstadoOrdenCambiado {
+                comprador_id: orden_compra.comprador_id,
+                vendedor_id: orden_compra.vendedor_id,
+                idx_orden,
+                estado_anterior,
+                estado_nuevo: orden_compra.estado.clone(),
+            });
+
+            Ok(orden_compra)
+        }
+
+        //El comprador solicita la cancelacion de una orden pendiente
+        #[ink(message)]
+        pub fn solicitar_cancelacion(
+            &mut self,
+            idx_orden: u32,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            self._solicitar_cancelacion(self.env().caller(), idx_orden)
+        }
+
+        //Funcion prueba solicitar_cancelacion()
+        fn _solicitar_cancelacion(
+            &mut self,
+            caller: AccountId,
+            idx_orden: u32,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            //Validacion de usuario
+            self._get_usuario(caller)?;
+
+            //Buscar orden de compra
+            let mut orden_compra = self.buscar_orden(idx_orden)?;
+
+            //Solo el comprador puede pedir la cancelacion
+            if orden_compra.comprador_id != caller {
+                return Err(ErrorSistema::NoEsCompradorDeLaOrden);
+            }
+
+            //Solo se cancelan ordenes pendientes
+            orden_compra.estado.transicionar(Estado::Cancelada)?;
+
+            if orden_compra.peticion_cancelacion {
+                return Err(ErrorSistema::CancelacionYaSolicitada);
+            }
+
+            //Una orden cuenta una sola vez aunque se vuelva a pedir tras un rechazo
+            if !orden_compra.cancelacion_solicitada {
+                self.actualizar_contadores_usuario(orden_compra.vendedor_id, |contadores| {
+                    contadores.ventas_con_peticion_cancelacion =
+                        contadores.ventas_con_peticion_cancelacion.saturating_add(1)
+                });
+            }
+            orden_compra.peticion_cancelacion = true;
+            orden_compra.cancelacion_solicitada = true;
+
+            //Reemplazar la orden de compra modificada
+            self.ordenes_compra.insert(idx_orden, &orden_compra);
+
+            self.env().emit_event(CancelacionSolicitada {
+                comprador_id: orden_compra.comprador_id,
+                vendedor_id: orden_compra.vendedor_id,
+                idx_orden,
+            });
+
+            Ok(orden_compra)
+        }
+
+        //El vendedor acepta la cancelacion solicitada por el comprador
+        #[ink(message)]
+        pub fn aceptar_cancelacion(&mut self, idx_orden: u32) -> Result<OrdenCompra, ErrorSistema> {
+            self._aceptar_cancelacion(self.env().caller(), idx_orden)
+        }
+
+        //Funcion prueba aceptar_cancelacion()
+        fn _aceptar_cancelacion(
+            &mut self,
+            caller: AccountId,
+            idx_orden: u32,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            //Validacion de usuario
+            self._get_usuario(caller)?;
+
+            //Buscar orden de compra
+            let mut orden_compra = self.buscar_orden(idx_orden)?;
+
+            //Solo el vendedor de la publicacion puede aceptar la cancelacion
+            if orden_compra.vendedor_id != caller {
+                return Err(ErrorSistema::NoEsVendedorDeLaOrden);
+            }
+
+            if !orden_compra.peticion_cancelacion {
+                return Err(ErrorSistema::CancelacionNoSolicitada);
+            }
+
+            self.cancelar_orden(idx_orden, &mut orden_compra)?;
+
+            self.env().emit_event(CancelacionResuelta {
+                comprador_id: orden_compra.comprador_id,
+                vendedor_id: orden_compra.vendedor_id,
+                idx_orden,
+                aceptada: true,
+            });
+
+            Ok(orden_compra)
+        }
+
+        //El vendedor rechaza la cancelacion, la orden sigue pendiente
+        #[ink(message)]
+        pub fn rechazar_cancelacion(
+            &mut self,
+            idx_orden: u32,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            self._rechazar_cancelacion(self.env().caller(), idx_orden)
+        }
+
+        //Funcion prueba rechazar_cancelacion()
+        fn _rechazar_cancelacion(
+            &mut self,
+            caller: AccountId,
+            idx_orden: u32,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            //Validacion de usuario
+            self._get_usuario(caller)?;
+
+            //Buscar orden de compra
+            let mut orden_compra = self.buscar_orden(idx_orden)?;
+
+            //Solo el vendedor de la publicacion puede rechazar la cancelacion
+            if orden_compra.vendedor_id != caller {
+                return Err(ErrorSistema::NoEsVendedorDeLaOrden);
+            }
+
+            if !orden_compra.peticion_cancelacion {
+                return Err(ErrorSistema::CancelacionNoSolicitada);
+            }
+
+            orden_compra.peticion_cancelacion = false;
+
+            //Reemplazar la orden de compra modificada
+            self.ordenes_compra.insert(idx_orden, &orden_compra);
+
+            self.env().emit_event(CancelacionResuelta {
+                comprador_id: orden_compra.comprador_id,
+                vendedor_id: orden_compra.vendedor_id,
+                idx_orden,
+                aceptada: false,
+            });
+
+            Ok(orden_compra)
+        }
+
+        //El comprador califica al vendedor de una orden recibida, de 1 a 5
+        #[ink(message)]
+        pub fn calificar_vendedor(
+            &mut self,
+            idx_orden: u32,
+            puntaje: u8,
+        ) -> Result<Reputacion, ErrorSistema> {
+            self._calificar_vendedor(self.env().caller(), idx_orden, puntaje)
+        }
+
+        //Funcion prueba calificar_vendedor()
+        fn _calificar_vendedor(
+            &mut self,
+            caller: AccountId,
+            idx_orden: u32,
+            puntaje: u8,
+        ) -> Result<Reputacion, ErrorSistema> {
+            let mut orden_compra = self.buscar_orden_a_calificar(caller, idx_orden, puntaje)?;
+
+            //Solo el comprador califica al vendedor
+            if orden_compra.comprador_id != caller {
+                return Err(ErrorSistema::NoEsCompradorDeLaOrden);
+            }
+            if orden_compra.calificada_por_comprador {
+                return Err(ErrorSistema::OrdenYaCalificada);
+            }
+
+            orden_compra.calificada_por_comprador = true;
+            self.ordenes_compra.insert(idx_orden, &orden_compra);
+
+            Ok(self.registrar_calificacion(caller, orden_compra.vendedor_id, idx_orden, puntaje))
+        }
+
+        //El vendedor califica al comprador de una orden recibida, de 1 a 5
+        #[ink(message)]
+        pub fn calificar_comprador(
+            &mut self,
+            idx_orden: u32,
+            puntaje: u8,
+        ) -> Result<Reputacion, ErrorSistema> {
+            self._calificar_comprador(self.env().caller(), idx_orden, puntaje)
+        }
+
+        //Funcion prueba calificar_comprador()
+        fn _calificar_comprador(
+            &mut self,
+            caller: AccountId,
+            idx_orden: u32,
+            puntaje: u8,
+        ) -> Result<Reputacion, ErrorSistema> {
+            let mut orden_compra = self.buscar_orden_a_calificar(caller, idx_orden, puntaje)?;
+
+            //Solo el vendedor califica al comprador
+            if orden_compra.vendedor_id != caller {
+                return Err(ErrorSistema::NoEsVendedorDeLaOrden);
+            }
+            if orden_compra.calificada_por_vendedor {
+                return Err(ErrorSistema::OrdenYaCalificada);
+            }
+
+            orden_compra.calificada_por_vendedor = true;
+            self.ordenes_compra.insert(idx_orden, &orden_compra);
+
+            Ok(self.registrar_calificacion(caller, orden_compra.comprador_id, idx_orden, puntaje))
+        }
+
+        //Retorna la reputacion de un usuario registrado
+        #[ink(message)]
+        pub fn get_reputacion(&self, account_id: AccountId) -> Result<Reputacion, ErrorSistema> {
+            self._get_usuario(account_id)?;
+            Ok(self.reputaciones.get(account_id).unwrap_or_default())
+        }
+
+        //Validaciones comunes a las calificaciones: usuario registrado, puntaje
+        //entre 1 y 5 y orden recibida
+        fn buscar_orden_a_calificar(
+            &self,
+            caller: AccountId,
+            idx_orden: u32,
+            puntaje: u8,
+        ) -> Result<OrdenCompra, ErrorSistema> {
+            //Validacion de usuario
+            self._get_usuario(caller)?;
+
+            if !(1..=5).contains(&puntaje) {
+                return Err(ErrorSistema::PuntajeInvalido);
+            }
+
+            let orden_compra = self.buscar_orden(idx_orden)?;
+            if orden_compra.estado != Estado::Recibida {
+                return Err(ErrorSistema::OrdenNoRecibida);
+            }
+
+            Ok(orden_compra)
+        }
+
+        //Suma el puntaje a la reputacion del calificado y emite el evento
+        fn registrar_calificacion(
+            &mut self,
+            calificador_id: AccountId,
+            calificado_id: AccountId,
+            idx_orden: u32,
+            puntaje: u8,
+        ) -> Reputacion {
+            let mut reputacion = self.reputaciones.get(calificado_id).unwrap_or_default();
+            reputacion.puntaje_total = reputacion.puntaje_total.saturating_add(u32::from(puntaje));
+            reputacion.cantidad_calificaciones =
+                reputacion.cantidad_calificaciones.saturating_add(1);
+            self.reputaciones.insert(calificado_id, &reputacion);
+
+            self.env().emit_event(CalificacionRegistrada {
+                calificador_id,
+                calificado_id,
+                idx_orden,
+                puntaje,
+            });
+
+            reputacion
+        }
+
+        //Valida que el solicitante sea el owner del contrato
+        fn solo_owner(&self, caller: AccountId) -> Result<(), ErrorSistema> {
+            if caller != self.owner {
+                return Err(ErrorSistema::NoAutorizado);
+            }
+            Ok(())
+        }
+
+        //Valida que el solicitante sea el owner o un admin
+        fn solo_admin(&self, caller: AccountId) -> Result<(), ErrorSistema> {
+            if caller != self.owner && !self.admins.contains(caller) {
+                return Err(ErrorSistema::NoAutorizado);
+            }
+            Ok(())
+        }
+
+        //Crea la categoria validando el nombre y que exista la categoria padre
+        fn agregar_categoria(
+            &mut self,
+            nombre: String,
+            id_padre: Option<u32>,
+        ) -> Result<Categoria, ErrorSistema> {
+            validar_nombre_categoria(&nombre)?;
+            if self.nombres_categorias.contains(&nombre) {
+                return Err(ErrorSistema::NombreCategoriaEnUso);
+            }
+            if let Some(id_padre) = id_padre {
+                self.buscar_categoria(id_padre)?;
+            }
+
+            let id_categoria = self.cantidad_categorias;
+            self.cantidad_categorias = id_categoria
+                .checked_add(1)
+                .ok_or(ErrorSistema::OverflowCategorias)?;
+
+            let categoria = Categoria {
+                id_categoria,
+                nombre,
+                id_padre,
+            };
+            self.categorias.insert(id_categoria, &categoria);
+            self.nombres_categorias
+                .insert(&categoria.nombre, &id_categoria);
+
+            self.env().emit_event(CategoriaCreada {
+                id_categoria,
+                nombre: categoria.nombre.clone(),
+                id_padre,
+            });
+
+            Ok(categoria)
+        }
+
+        //Retorna el id de la categoria y los de todas sus subcategorias, a cualquier
+        //profundidad. Como el padre tiene que existir al crear una categoria, siempre
+        //tiene un id menor que sus hijas y alcanza con recorrerlas una vez en orden
+        fn ids_categoria_y_subcategorias(&self, id_categoria: u32) -> Vec<u32> {
+            let mut ids = Vec::from([id_categoria]);
+            for categoria in (id_categoria.saturating_add(1)..self.cantidad_categorias)
+                .filter_map(|i| self.categorias.get(i))
+            {
+                if categoria
+                    .id_padre
+                    .is_some_and(|id_padre| ids.contains(&id_padre))
+                {
+                    ids.push(categoria.id_categoria);
+                }
+            }
+            ids
+        }
+
+        //Retorna una copia de la categoria si existe
+        fn buscar_categoria(&self, id_categoria: u32) -> Result<Categoria, ErrorSistema> {
+            self.categorias
+                .get(id_categoria)
+                .ok_or(ErrorSistema::CategoriaNoExistente)
+        }
+
+        //Guarda los cambios de un usuario y emite el evento
+        fn actualizar_usuario(&mut self, usuario: &Usuario) {
+            self.usuarios.insert(usuario.account_id, usuario);
+
+            self.env().emit_event(UsuarioActualizado {
+                account_id: usuario.account_id,
+                username: usuario.username.clone(),
+                rol: usuario.rol.clone(),
+            });
+        }
+
+        //Suma una orden recibida a los contadores del vendedor, de la categoria de
+        //la publicacion y del Marketplace
+        fn registrar_venta(&mut self, orden_compra: &OrdenCompra) {
+            let monto = Balance::from(orden_compra.precio_unitario)
+                .saturating_mul(Balance::from(orden_compra.cantidad));
+
+            if let Some(publicacion) = self.publicaciones.get(orden_compra.id_publicacion) {
+                let mut categoria = self
+                    .contadores_categorias
+                    .get(publicacion.id_categoria)
+                    .unwrap_or_default();
+                categoria.sumar_venta(orden_compra.cantidad, monto);
+                self.contadores_categorias
+                    .insert(publicacion.id_categoria, &categoria);
+            }
+            self.contadores_ventas
+                .sumar_venta(orden_compra.cantidad, monto);
+
+            self.actualizar_contadores_usuario(orden_compra.vendedor_id, |vendedor| {
+                vendedor.ventas_recibidas = vendedor.ventas_recibidas.saturating_add(1);
+                vendedor.total_vendido = vendedor.total_vendido.saturating_add(monto);
+            });
+        }
+
+        //Lee, modifica y guarda los contadores de un usuario
+        fn actualizar_contadores_usuario(
+            &mut self,
+            account_id: AccountId,
+            actualizar: impl FnOnce(&mut ContadoresUsuario),
+        ) {
+            let mut contadores = self.contadores_usuarios.get(account_id).unwrap_or_default();
+            actualizar(&mut contadores);
+            self.contadores_usuarios.insert(account_id, &contadores);
+        }
+
+        //Suma o resta una publicacion activa en su categoria y en el total
+        fn actualizar_publicaciones_activas(&mut self, id_categoria: u32, activa: bool) {
+            let mut categoria = self
+                .contadores_categorias
+                .get(id_categoria)
+                .unwrap_or_default();
+            categoria.actualizar_publicaciones_activas(activa);
+            self.contadores_categorias.insert(id_categoria, &categoria);
+            self.contadores_ventas
+                .actualizar_publicaciones_activas(activa);
+        }
+
+        //Retorna los ids de las ordenes recibidas por el vendedor, en orden de creacion
+        fn ids_ordenes_vendedor(&self, vendedor_id: AccountId) -> Vec<u32> {
+            let cantidad = self.cantidad_ordenes_vendedor.get(vendedor_id).unwrap_or(0);
+            (0..cantidad)
+                .filter_map(|posicion| self.ordenes_vendedor_mapping.get((vendedor_id, posicion)))
+                .collect()
+        }
+
+        //Indica si alguna de las ordenes esta pendiente o enviada
+        fn tiene_ordenes_en_curso(&self, ids_ordenes: &[u32]) -> bool {
+            ids_ordenes
+                .iter()
+                .filter_map(|&i| self.ordenes_compra.get(i))
+                .any(|orden| matches!(orden.estado, Estado::Pendiente | Estado::Enviada))
+        }
+
+        //Cancela una orden pendiente: repone el stock de la publicacion, reintegra
+        //el pago al comprador y guarda la orden
+        fn cancelar_orden(
+            &mut self,
+            idx_orden: u32,
+            orden_compra: &mut OrdenCompra,
+        ) -> Result<(), ErrorSistema> {
+            let estado_anterior = orden_compra.estado.clone();
+            orden_compra.estado = orden_compra.estado.transicionar(Estado::Cancelada)?;
+            orden_compra.peticion_cancelacion = false;
+
+            //Devolver el stock a la publicacion original
+            let idx_publicacion = orden_compra.id_publicacion;
+            let mut publicacion = self.buscar_publicacion(idx_publicacion)?;
+            publicacion.stock = publicacion
+                .stock
+                .checked_add(orden_compra.cantidad)
+                .ok_or(ErrorSistema::OverflowStock)?;
+            publicacion.unidades_vendidas = publicacion
+                .unidades_vendidas
+                .saturating_sub(orden_compra.cantidad);
+            self.publicaciones.insert(idx_publicacion, &publicacion);
+
+            //Reintegra el pago retenido al comprador
+            self.liberar_fondos(idx_orden, orden_compra.comprador_id)?;
+
+            //Reemplazar la orden de compra modificada
+            self.ordenes_compra.insert(idx_orden, orden_compra);
+            self.actualizar_contadores_usuario(orden_compra.vendedor_id, |contadores| {
+                contadores.ventas_canceladas = contadores.ventas_canceladas.saturating_add(1)
+            });
+            let monto = Balance::from(orden_compra.precio_unitario)
+                .saturating_mul(Balance::from(orden_compra.cantidad));
+            self.actualizar_contadores_usuario(orden_compra.comprador_id, |contadores| {
+                contadores.compras = contadores.compras.saturating_sub(1);
+                contadores.total_gastado = contadores.total_gastado.saturating_sub(monto);
+            });
+
+            self.emitir_publicacion_actualizada(idx_publicacion, &publicacion);
+            self.env().emit_event(EstadoOrdenCambiado {
+                comprador_id: orden_compra.comprador_id,
+                vendedor_id: orden_compra.vendedor_id,
+                idx_orden,
+                estado_anterior,
+                estado_nuevo: orden_compra.estado.clone(),
+            });
+
+            Ok(())
+        }
+
+        //Valida que el usuario no tenga una suspension vigente
+        fn validar_no_suspendido(&self, account_id: AccountId) -> Result<(), ErrorSistema> {
+            if self.get_suspension(account_id).is_some() {
+                return Err(ErrorSistema::UsuarioSuspendido);
+            }
+            Ok(())
+        }
+
+        //Transfiere el pago retenido de una orden al destinatario y lo quita del escrow
+        fn liberar_fondos(
+            &mut self,
+            idx_orden: u32,
+            destinatario: AccountId,
+        ) -> Result<(), ErrorSistema> {
+            let monto = self.fondos_retenidos.take(idx_orden).unwrap_or_default();
+            if monto > 0 {
+                self.env()
+                    .transfer(destinatario, monto)
+                    .map_err(|_| ErrorSistema::TransferenciaFallida)?;
+            }
+            Ok(())
+        }
+
+        //Retorna una copia de la publicacion si existe
+        fn buscar_publicacion(&self, idx_publicacion: u32) -> Result<Publicacion, ErrorSistema> {
+            self.publicaciones
+                .get(idx_publicacion)
+                .ok_or(ErrorSistema::PublicacionNoExistente)
+        }
+
+        //Retorna la publicacion validando que el solicitante sea su vendedor
+        fn buscar_publicacion_del_vendedor(
+            &self,
+            caller: AccountId,
+            idx_publicacion: u32,
+        ) -> Result<Publicacion, ErrorSistema> {
+            //Validacion de usuario
+            let usuario = self._get_usuario(caller)?;
+            usuario.es_vendedor()?;
+
+            let publicacion = self.buscar_publicacion(idx_publicacion)?;
+            if publicacion.vendedor_id != caller {
+                return Err(ErrorSistema::NoEsVendedorDeLaPublicacion);
+            }
+
+            Ok(publicacion)
+        }
+
+        fn emitir_publicacion_actualizada(&self, idx_publicacion: u32, publicacion: &Publicacion) {
+            self.env().emit_event(PublicacionActualizada {
+                vendedor_id: publicacion.vendedor_id,
+                idx_publicacion,
+                precio: publicacion.precio,
+                stock: publicacion.stock,
+                activa: publicacion.activa,
+            });
+        }
+
+        //Retorna una copia de la orden de compra si existe
+        fn buscar_orden(&self, idx_orden: u32) -> Result<OrdenCompra, ErrorSistema> {
+            self.ordenes_compra
+                .get(idx_orden)
+                .ok_or(ErrorSistema::OrdenNoExistente)
+        }
+    }
+
+    impl Default for Marketplace {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    //Valida que el username tenga entre MIN_LARGO_USERNAME y MAX_LARGO_USERNAME
+    //caracteres y solo use minusculas, digitos o guion bajo
+    fn validar_username(username: &str) -> Result<(), ErrorSistema> {
+        if !(MIN_LARGO_USERNAME..=MAX_LARGO_USERNAME).contains(&username.chars().count()) {
+            return Err(ErrorSistema::UsernameLargoInvalido);
+        }
+        if !username
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(ErrorSistema::UsernameCaracterInvalido);
+        }
+        Ok(())
+    }
+
+    //Valida que el nombre de la categoria no este vacio ni supere
+    //MAX_LARGO_NOMBRE_CATEGORIA caracteres
+    fn validar_nombre_categoria(nombre: &str) -> Result<(), ErrorSistema> {
+        let largo = nombre.trim().chars().count();
+        if largo == 0 || nombre.chars().count() > MAX_LARGO_NOMBRE_CATEGORIA {
+            return Err(ErrorSistema::NombreCategoriaInvalido);
+        }
+        Ok(())
+    }
+
+    //Calcula el rango [inicio, fin) de una pagina sobre `total` elementos,
+    //con el limite acotado a MAX_LIMITE_PAGINA
+    fn rango_pagina(total: u32, offset: u32, limit: u32) -> (u32, u32) {
+        let inicio = offset.min(total);
+        let fin = inicio
+            .saturating_add(limit.min(MAX_LIMITE_PAGINA))
+            .min(total);
+        (inicio, fin)
+    }
+
+    impl Publicacion {
+        //Arranca en el bloque 0, el contrato asigna el bloque de creacion al publicar
+        pub fn new(
+            id_publicacion: u64,
+            nombre_producto: String,
+            descripcion: String,
+            precio: u64,
+            id_categoria: u32,
+            stock: u64,
+            vendedor_id: AccountId,
+        ) -> Publicacion {
+            Publicacion {
+                id_publicacion,
+                nombre_producto,
+                descripcion,
+                precio,
+                id_categoria,
+                stock,
+                vendedor_id,
+                activa: true,
+                unidades_vendidas: 0,
+                bloque_creacion: 0,
+                dada_de_baja: false,
+            }
+        }
+    }
+
+    impl ContadoresVentas {
+        fn actualizar_publicaciones_activas(&mut self, activa: bool) {
+            self.publicaciones_activas = if activa {
+                self.publicaciones_activas.saturating_add(1)
+            } else {
+                self.publicaciones_activas.saturating_sub(1)
+            };
+        }
+
+        fn sumar_venta(&mut self, unidades: u64, monto: Balance) {
+            self.ordenes_recibidas = self.ordenes_recibidas.saturating_add(1);
+            self.unidades_vendidas = self.unidades_vendidas.saturating_add(unidades);
+            self.total_vendido = self.total_vendido.saturating_add(monto);
+        }
+    }
+
+    impl Estado {
+        //Valida la transicion al nuevo estado y lo retorna si es legal
+        //Pendiente -> Enviada -> Recibida, Pendiente -> Cancelada
+        //Recibida y Cancelada son finales
+        fn transicionar(&self, nuevo: Estado) -> Result<Estado, ErrorSistema> {
+            match (self, &nuevo) {
+                (Estado::Pendiente, Estado::Enviada)
+                | (Estado::Pendiente, Estado::Cancelada)
+                | (Estado::Enviada, Estado::Recibida) => Ok(nuevo),
+                (Estado::Cancelada, _) => Err(ErrorSistema::OrdenCancelada),
+                (Estado::Recibida, _) => Err(ErrorSistema::OrdenYaRecibida),
+                (_, Estado::Recibida) => Err(ErrorSistema::OrdenNoEnviada),
+                _ => Err(ErrorSistema::OrdenNoPendiente),
+            }
+        }
+    }
+
+    impl Usuario {
+        //Valida que el usuario tenga rol Vendedor o Ambos
+        fn es_vendedor(&self) -> Result<bool, ErrorSistema> {
+            if matches!(self.rol, Rol::Comprador) {
+                Err(ErrorSistema::UsuarioNoEsVendedor)
+            } else {
+                Ok(true)
+            }
+        }
+
+        //Valida que el usuario tenga rol Comprador o Ambos
+        fn es_comprador(&self) -> Result<bool, ErrorSistema> {
+            if matches!(self.rol, Rol::Vendedor) {
+                Err(ErrorSistema::UsuarioNoEsComprador)
+            } else {
+                Ok(true)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        //Ids de CATEGORIAS_INICIALES
+        const COMPUTACION: u32 = 0;
+        const ROPA: u32 = 1;
+        const HERRAMIENTAS: u32 = 2;
+        const MUEBLES: u32 = 3;
+
+        mod tests_es_vendedor {
+            use super::*;
+
+            #[test]
+            fn tests_es_vendedor_true_vendedor() {
+                let usuario = Usuario {
+                    account_id: AccountId::from([0xAA; 32]),
+                    username: "agustin22".to_string(),
+                    rol: Rol::Vendedor,
+                    bloque_registro: 0,
+                };
+
+                assert!(usuario.es_vendedor().is_ok());
+            }
+
+            #[test]
+            fn tests_es_vendedor_true_ambos() {
+                let usuario = Usuario {
+                    account_id: AccountId::from([0xAA; 32]),
+                    username: "agustin22".to_string(),
+                    rol: Rol::Ambos,
+                    bloque_registro: 0,
+                };
+
+                assert!(usuario.es_vendedor().is_ok());
+            }
+
+            #[test]
             fn tests_es_vendedor_false() {
                 let usuario = Usuario {
                     account_id: AccountId::from([0xAA; 32]),
                     username: "agustin22".to_string(),
-                    rol: Rol::Comprador,
+                    rol: Rol::Comprador,
+                    bloque_registro: 0,
+                };
+
+                assert!(usuario.es_vendedor().is_err());
+            }
+        }
+
+        mod tests_es_comprador {
+            use super::*;
+
+            #[test]
+            fn tests_es_comprador_true_comprador() {
+                let usuario = Usuario {
+                    account_id: AccountId::from([0xAA; 32]),
+                    username: "agustin22".to_string(),
+                    rol: Rol::Comprador,
+                    bloque_registro: 0,
+                };
+
+                assert!(usuario.es_comprador().is_ok());
+            }
+
+            #[test]
+            fn tests_es_comprador_true_ambos() {
+                let usuario = Usuario {
+                    account_id: AccountId::from([0xAA; 32]),
+                    username: "agustin22".to_string(),
+                    rol: Rol::Ambos,
+                    bloque_registro: 0,
+                };
+
+                assert!(usuario.es_comprador().is_ok());
+            }
+
+            #[test]
+            fn tests_es_comprador_false() {
+                let usuario = Usuario {
+                    account_id: AccountId::from([0xAA; 32]),
+                    username: "agustin22".to_string(),
+                    rol: Rol::Vendedor,
+                    bloque_registro: 0,
+                };
+
+                assert!(usuario.es_comprador().is_err());
+            }
+        }
+
+        mod tests_registrar_usuario {
+            use super::*;
+
+            #[ink::test]
+            fn tests_registrar_usuario_no_registrado() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                assert!(marketplace
+                    ._registrar_usuario(caller, username, rol)
+                    .is_ok());
+            }
+
+            #[ink::test]
+            fn tests_registrar_usuario_ya_registrado_error() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                assert!(marketplace
+                    ._registrar_usuario(caller, username.clone(), rol.clone())
+                    .is_ok());
+
+                let result = marketplace._registrar_usuario(caller, username, rol);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioYaRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_registrar_usuario_username_en_uso() {
+                let mut marketplace = Marketplace::new();
+
+                let primero = AccountId::from([0xAA; 32]);
+                let segundo = AccountId::from([0xBB; 32]);
+                let _ =
+                    marketplace._registrar_usuario(primero, "agustin".to_string(), Rol::Vendedor);
+
+                let result =
+                    marketplace._registrar_usuario(segundo, "agustin".to_string(), Rol::Vendedor);
+
+                assert_eq!(result, Err(ErrorSistema::UsernameEnUso));
+                assert_eq!(
+                    marketplace._get_usuario(segundo),
+                    Err(ErrorSistema::UsuarioNoRegistrado)
+                );
+            }
+
+            #[ink::test]
+            fn tests_registrar_usuario_username_largo_invalido() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+
+                assert_eq!(
+                    marketplace._registrar_usuario(caller, "ab".to_string(), Rol::Ambos),
+                    Err(ErrorSistema::UsernameLargoInvalido)
+                );
+                assert_eq!(
+                    marketplace._registrar_usuario(caller, "a".repeat(33), Rol::Ambos),
+                    Err(ErrorSistema::UsernameLargoInvalido)
+                );
+                assert!(marketplace
+                    ._registrar_usuario(caller, "a".repeat(32), Rol::Ambos)
+                    .is_ok());
+            }
+
+            #[ink::test]
+            fn tests_registrar_usuario_username_caracter_invalido() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+
+                for username in ["Agustin", "agustin perez", "agustin!", "agustín"] {
+                    assert_eq!(
+                        marketplace._registrar_usuario(caller, username.to_string(), Rol::Ambos),
+                        Err(ErrorSistema::UsernameCaracterInvalido)
+                    );
+                }
+                assert!(marketplace
+                    ._registrar_usuario(caller, "agustin_99".to_string(), Rol::Ambos)
+                    .is_ok());
+            }
+        }
+
+        mod tests_get_usuario {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_usuario_encontrado() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                assert!(marketplace._get_usuario(caller).is_ok());
+            }
+
+            #[ink::test]
+            fn tests_get_usuario_no_encontrado() {
+                let marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+
+                let result = marketplace._get_usuario(caller);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+        }
+
+        mod tests_categorias_iniciales {
+            use super::*;
+
+            #[ink::test]
+            fn tests_categorias_iniciales_creadas_por_el_constructor() {
+                let marketplace = Marketplace::new();
+
+                let nombres: Vec<String> = marketplace
+                    .get_categorias()
+                    .into_iter()
+                    .map(|categoria| categoria.nombre)
+                    .collect();
+
+                assert_eq!(nombres, CATEGORIAS_INICIALES);
+                assert_eq!(marketplace.get_owner(), owner());
+                assert_eq!(
+                    ink::env::test::recorded_events().count(),
+                    CATEGORIAS_INICIALES.len()
+                );
+            }
+        }
+
+        mod tests_admins {
+            use super::*;
+
+            #[ink::test]
+            fn tests_agregar_admin_correcto() {
+                let mut marketplace = Marketplace::new();
+                let admin = AccountId::from([0xAD; 32]);
+
+                assert!(!marketplace.es_admin(admin));
+                assert_eq!(marketplace._agregar_admin(owner(), admin), Ok(()));
+
+                assert!(marketplace.es_admin(admin));
+                assert!(marketplace
+                    ._crear_categoria(admin, "Electrodomesticos".to_string(), None)
+                    .is_ok());
+            }
+
+            #[ink::test]
+            fn tests_agregar_admin_solo_owner() {
+                let mut marketplace = Marketplace::new();
+                let admin = AccountId::from([0xAD; 32]);
+                let otro = AccountId::from([0xAE; 32]);
+                let _ = marketplace._agregar_admin(owner(), admin);
+
+                //Un admin no puede agregar otros admins
+                assert_eq!(
+                    marketplace._agregar_admin(admin, otro),
+                    Err(ErrorSistema::NoAutorizado)
+                );
+                assert!(!marketplace.es_admin(otro));
+            }
+
+            #[ink::test]
+            fn tests_quitar_admin_correcto() {
+                let mut marketplace = Marketplace::new();
+                let admin = AccountId::from([0xAD; 32]);
+                let _ = marketplace._agregar_admin(owner(), admin);
+
+                assert_eq!(marketplace._quitar_admin(owner(), admin), Ok(()));
+
+                assert!(!marketplace.es_admin(admin));
+                assert_eq!(
+                    marketplace._crear_categoria(admin, "Electrodomesticos".to_string(), None),
+                    Err(ErrorSistema::NoAutorizado)
+                );
+            }
+
+            #[ink::test]
+            fn tests_quitar_admin_solo_owner() {
+                let mut marketplace = Marketplace::new();
+                let admin = AccountId::from([0xAD; 32]);
+                let _ = marketplace._agregar_admin(owner(), admin);
+
+                assert_eq!(
+                    marketplace._quitar_admin(admin, admin),
+                    Err(ErrorSistema::NoAutorizado)
+                );
+                assert!(marketplace.es_admin(admin));
+            }
+        }
+
+        mod tests_transferir_ownership {
+            use super::*;
+
+            #[ink::test]
+            fn tests_transferir_ownership_en_dos_pasos() {
+                let mut marketplace = Marketplace::new();
+                let nuevo_owner = AccountId::from([0xAD; 32]);
+
+                assert_eq!(
+                    marketplace._transferir_ownership(owner(), nuevo_owner),
+                    Ok(())
+                );
+                //Hasta que acepte el owner sigue siendo el anterior
+                assert_eq!(marketplace.get_owner(), owner());
+
+                assert_eq!(marketplace._aceptar_ownership(nuevo_owner), Ok(()));
+
+                assert_eq!(marketplace.get_owner(), nuevo_owner);
+                assert!(!marketplace.es_admin(owner()));
+                assert_eq!(
+                    marketplace._agregar_admin(owner(), owner()),
+                    Err(ErrorSistema::NoAutorizado)
+                );
+            }
+
+            #[ink::test]
+            fn tests_transferir_ownership_solo_owner() {
+                let mut marketplace = Marketplace::new();
+                let otro = AccountId::from([0xAD; 32]);
+
+                assert_eq!(
+                    marketplace._transferir_ownership(otro, otro),
+                    Err(ErrorSistema::NoAutorizado)
+                );
+            }
+
+            #[ink::test]
+            fn tests_aceptar_ownership_no_propuesto() {
+                let mut marketplace = Marketplace::new();
+                let propuesto = AccountId::from([0xAD; 32]);
+                let otro = AccountId::from([0xAE; 32]);
+
+                assert_eq!(
+                    marketplace._aceptar_ownership(propuesto),
+                    Err(ErrorSistema::NoAutorizado)
+                );
+
+                let _ = marketplace._transferir_ownership(owner(), propuesto);
+                assert_eq!(
+                    marketplace._aceptar_ownership(otro),
+                    Err(ErrorSistema::NoAutorizado)
+                );
+                assert_eq!(marketplace.get_owner(), owner());
+            }
+
+            #[ink::test]
+            fn tests_transferir_ownership_reemplaza_propuesta() {
+                let mut marketplace = Marketplace::new();
+                let primero = AccountId::from([0xAD; 32]);
+                let segundo = AccountId::from([0xAE; 32]);
+
+                let _ = marketplace._transferir_ownership(owner(), primero);
+                let _ = marketplace._transferir_ownership(owner(), segundo);
+
+                assert_eq!(
+                    marketplace._aceptar_ownership(primero),
+                    Err(ErrorSistema::NoAutorizado)
+                );
+                assert_eq!(marketplace._aceptar_ownership(segundo), Ok(()));
+            }
+        }
+
+        mod tests_crear_categoria {
+            use super::*;
+
+            #[ink::test]
+            fn tests_crear_categoria_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let result =
+                    marketplace._crear_categoria(owner(), "Electrodomesticos".to_string(), None);
+
+                let esperada = Categoria {
+                    id_categoria: 4,
+                    nombre: "Electrodomesticos".to_string(),
+                    id_padre: None,
+                };
+                assert_eq!(result, Ok(esperada.clone()));
+                assert_eq!(marketplace.get_categorias().last(), Some(&esperada));
+            }
+
+            #[ink::test]
+            fn tests_crear_categoria_subcategoria() {
+                let mut marketplace = Marketplace::new();
+
+                let result = marketplace._crear_categoria(
+                    owner(),
+                    "Notebooks".to_string(),
+                    Some(COMPUTACION),
+                );
+
+                assert_eq!(result.map(|c| c.id_padre), Ok(Some(COMPUTACION)));
+            }
+
+            #[ink::test]
+            fn tests_crear_categoria_padre_no_existente() {
+                let mut marketplace = Marketplace::new();
+
+                let result =
+                    marketplace._crear_categoria(owner(), "Notebooks".to_string(), Some(9));
+
+                assert_eq!(result, Err(ErrorSistema::CategoriaNoExistente));
+                assert_eq!(marketplace.cantidad_categorias, 4);
+            }
+
+            #[ink::test]
+            fn tests_crear_categoria_nombre_invalido() {
+                let mut marketplace = Marketplace::new();
+
+                assert_eq!(
+                    marketplace._crear_categoria(owner(), "   ".to_string(), None),
+                    Err(ErrorSistema::NombreCategoriaInvalido)
+                );
+                assert_eq!(
+                    marketplace._crear_categoria(owner(), "a".repeat(33), None),
+                    Err(ErrorSistema::NombreCategoriaInvalido)
+                );
+            }
+
+            #[ink::test]
+            fn tests_crear_categoria_nombre_en_uso() {
+                let mut marketplace = Marketplace::new();
+                let _ = marketplace._crear_categoria(
+                    owner(),
+                    "Notebooks".to_string(),
+                    Some(COMPUTACION),
+                );
+
+                //Repetido como hermana y bajo otra categoria padre
+                assert_eq!(
+                    marketplace._crear_categoria(
+                        owner(),
+                        "Notebooks".to_string(),
+                        Some(COMPUTACION)
+                    ),
+                    Err(ErrorSistema::NombreCategoriaEnUso)
+                );
+                assert_eq!(
+                    marketplace._crear_categoria(owner(), "Ropa".to_string(), Some(MUEBLES)),
+                    Err(ErrorSistema::NombreCategoriaEnUso)
+                );
+                assert_eq!(marketplace.cantidad_categorias, 5);
+            }
+
+            #[ink::test]
+            fn tests_crear_categoria_no_autorizado() {
+                let mut marketplace = Marketplace::new();
+
+                let result = marketplace._crear_categoria(
+                    AccountId::from([0xAA; 32]),
+                    "Electrodomesticos".to_string(),
+                    None,
+                );
+
+                assert_eq!(result, Err(ErrorSistema::NoAutorizado));
+            }
+        }
+
+        mod tests_renombrar_categoria {
+            use super::*;
+
+            #[ink::test]
+            fn tests_renombrar_categoria_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let result =
+                    marketplace._renombrar_categoria(owner(), ROPA, "Indumentaria".to_string());
+
+                assert_eq!(result.map(|c| c.nombre), Ok("Indumentaria".to_string()));
+                assert_eq!(
+                    marketplace.get_categorias()[ROPA as usize].nombre,
+                    "Indumentaria"
+                );
+            }
+
+            #[ink::test]
+            fn tests_renombrar_categoria_nombre_en_uso() {
+                let mut marketplace = Marketplace::new();
+
+                let result = marketplace._renombrar_categoria(owner(), ROPA, "Muebles".to_string());
+
+                assert_eq!(result, Err(ErrorSistema::NombreCategoriaEnUso));
+                assert_eq!(marketplace.get_categorias()[ROPA as usize].nombre, "Ropa");
+            }
+
+            #[ink::test]
+            fn tests_renombrar_categoria_libera_nombre_anterior() {
+                let mut marketplace = Marketplace::new();
+
+                //Mantener el mismo nombre no choca consigo misma
+                assert!(marketplace
+                    ._renombrar_categoria(owner(), ROPA, "Ropa".to_string())
+                    .is_ok());
+                let _ = marketplace._renombrar_categoria(owner(), ROPA, "Indumentaria".to_string());
+
+                let result = marketplace._crear_categoria(owner(), "Ropa".to_string(), None);
+
+                assert_eq!(result.map(|c| c.id_categoria), Ok(4));
+            }
+
+            #[ink::test]
+            fn tests_renombrar_categoria_no_existente() {
+                let mut marketplace = Marketplace::new();
+
+                let result =
+                    marketplace._renombrar_categoria(owner(), 9, "Indumentaria".to_string());
+
+                assert_eq!(result, Err(ErrorSistema::CategoriaNoExistente));
+            }
+
+            #[ink::test]
+            fn tests_renombrar_categoria_no_autorizado() {
+                let mut marketplace = Marketplace::new();
+
+                let result = marketplace._renombrar_categoria(
+                    AccountId::from([0xAA; 32]),
+                    ROPA,
+                    "Indumentaria".to_string(),
+                );
+
+                assert_eq!(result, Err(ErrorSistema::NoAutorizado));
+                assert_eq!(marketplace.get_categorias()[ROPA as usize].nombre, "Ropa");
+            }
+        }
+
+        mod tests_get_perfil {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_perfil_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                let _ =
+                    marketplace._registrar_usuario(comprador, "juan".to_string(), Rol::Comprador);
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+                let _ =
+                    marketplace._registrar_usuario(vendedor, "agustin".to_string(), Rol::Vendedor);
+                for _ in 0..2 {
+                    let _ = marketplace._publicar(
+                        vendedor,
+                        "Remera".to_string(),
+                        "algodon".to_string(),
+                        12000,
+                        ROPA,
+                        20,
+                    );
+                }
+                let _ = marketplace._ordenar_compra(comprador, 0_u32, 1, 12000);
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
+                let _ = marketplace._calificar_vendedor(comprador, 0_u32, 4);
+
+                let perfil = marketplace.get_perfil(vendedor);
+
+                assert_eq!(
+                    perfil,
+                    Ok(Perfil {
+                        account_id: vendedor,
+                        username: "agustin".to_string(),
+                        rol: Rol::Vendedor,
+                        reputacion: Reputacion {
+                            puntaje_total: 4,
+                            cantidad_calificaciones: 1,
+                        },
+                        cantidad_publicaciones: 2,
+                        bloque_registro: 2,
+                    })
+                );
+                assert_eq!(
+                    marketplace.get_perfil(comprador).map(|p| p.bloque_registro),
+                    Ok(0)
+                );
+            }
+
+            #[ink::test]
+            fn tests_get_perfil_omite_publicaciones_ocultas() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+                for _ in 0..2 {
+                    let _ = marketplace._publicar(
+                        vendedor,
+                        "Pantalon".to_string(),
+                        "jean".to_string(),
+                        20000,
+                        ROPA,
+                        5,
+                    );
+                }
+                let _ = marketplace._pausar_publicacion(vendedor, 1);
+                let _ = marketplace._dar_de_baja_publicacion(owner(), 2, "estafa".to_string());
+
+                let perfil = marketplace.get_perfil(vendedor).unwrap();
+
+                assert_eq!(perfil.cantidad_publicaciones, 1);
+            }
+
+            #[ink::test]
+            fn tests_get_perfil_usuario_no_registrado() {
+                let marketplace = Marketplace::new();
+
+                let result = marketplace.get_perfil(AccountId::from([0xAA; 32]));
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+        }
+
+        mod tests_cambiar_rol {
+            use super::*;
+
+            #[ink::test]
+            fn tests_cambiar_rol_comprador_a_ambos() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result = marketplace._cambiar_rol(comprador, Rol::Ambos);
+
+                assert_eq!(result.map(|u| u.rol), Ok(Rol::Ambos));
+                assert_eq!(marketplace._get_usuario(comprador).unwrap().rol, Rol::Ambos);
+
+                //Ya puede publicar con la misma cuenta
+                let publicacion = marketplace._publicar(
+                    comprador,
+                    "Silla".to_string(),
+                    "madera".to_string(),
+                    5000,
+                    MUEBLES,
+                    2,
+                );
+                assert!(publicacion.is_ok());
+            }
+
+            #[ink::test]
+            fn tests_cambiar_rol_vendedor_con_publicaciones_activas() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._cambiar_rol(vendedor, Rol::Comprador);
+
+                assert_eq!(result, Err(ErrorSistema::VendedorConPublicacionesActivas));
+                assert_eq!(
+                    marketplace._get_usuario(vendedor).unwrap().rol,
+                    Rol::Vendedor
+                );
+            }
+
+            #[ink::test]
+            fn tests_cambiar_rol_vendedor_con_ordenes_en_curso() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+                let _ = marketplace._pausar_publicacion(vendedor, 0);
+
+                let result = marketplace._cambiar_rol(vendedor, Rol::Comprador);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioConOrdenesEnCurso));
+            }
+
+            #[ink::test]
+            fn tests_cambiar_rol_vendedor_a_comprador_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._pausar_publicacion(vendedor, 0);
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
+
+                let result = marketplace._cambiar_rol(vendedor, Rol::Comprador);
+
+                assert_eq!(result.map(|u| u.rol), Ok(Rol::Comprador));
+            }
+
+            #[ink::test]
+            fn tests_cambiar_rol_comprador_con_ordenes_en_curso() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let result = marketplace._cambiar_rol(comprador, Rol::Vendedor);
+                assert_eq!(result, Err(ErrorSistema::UsuarioConOrdenesEnCurso));
+
+                //Pasar a Ambos no deja a nadie sin rol
+                assert!(marketplace._cambiar_rol(comprador, Rol::Ambos).is_ok());
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
+                assert!(marketplace._cambiar_rol(comprador, Rol::Vendedor).is_ok());
+            }
+
+            #[ink::test]
+            fn tests_cambiar_rol_usuario_no_registrado() {
+                let mut marketplace = Marketplace::new();
+
+                let result = marketplace._cambiar_rol(AccountId::from([0xAA; 32]), Rol::Ambos);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+        }
+
+        mod tests_editar_username {
+            use super::*;
+
+            #[ink::test]
+            fn tests_editar_username_correcto() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._editar_username(vendedor, "agustin_tienda".to_string());
+
+                assert_eq!(result.map(|u| u.username), Ok("agustin_tienda".to_string()));
+                let usuario = marketplace._get_usuario(vendedor).unwrap();
+                assert_eq!(usuario.username, "agustin_tienda");
+                assert_eq!(usuario.rol, Rol::Vendedor);
+            }
+
+            #[ink::test]
+            fn tests_editar_username_libera_el_anterior() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._editar_username(vendedor, "agustin_tienda".to_string());
+
+                assert_eq!(
+                    marketplace.buscar_usuario_por_username("agustin".to_string()),
+                    None
+                );
+                assert!(marketplace
+                    ._editar_username(comprador, "agustin".to_string())
+                    .is_ok());
+                assert_eq!(
+                    marketplace
+                        .buscar_usuario_por_username("agustin".to_string())
+                        .map(|u| u.account_id),
+                    Some(comprador)
+                );
+            }
+
+            #[ink::test]
+            fn tests_editar_username_en_uso() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._editar_username(vendedor, "juan".to_string());
+
+                assert_eq!(result, Err(ErrorSistema::UsernameEnUso));
+                assert_eq!(
+                    marketplace._get_usuario(vendedor).unwrap().username,
+                    "agustin"
+                );
+            }
+
+            #[ink::test]
+            fn tests_editar_username_mismo_username() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._editar_username(vendedor, "agustin".to_string());
+
+                assert!(result.is_ok());
+                assert_eq!(
+                    marketplace
+                        .buscar_usuario_por_username("agustin".to_string())
+                        .map(|u| u.account_id),
+                    Some(vendedor)
+                );
+            }
+
+            #[ink::test]
+            fn tests_editar_username_invalido() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                assert_eq!(
+                    marketplace._editar_username(vendedor, "ag".to_string()),
+                    Err(ErrorSistema::UsernameLargoInvalido)
+                );
+                assert_eq!(
+                    marketplace._editar_username(vendedor, "Agustin".to_string()),
+                    Err(ErrorSistema::UsernameCaracterInvalido)
+                );
+            }
+
+            #[ink::test]
+            fn tests_editar_username_usuario_no_registrado() {
+                let mut marketplace = Marketplace::new();
+
+                let result =
+                    marketplace._editar_username(AccountId::from([0xAA; 32]), "juan".to_string());
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+        }
+
+        mod tests_buscar_usuario_por_username {
+            use super::*;
+
+            #[ink::test]
+            fn tests_buscar_usuario_por_username_encontrado() {
+                let (marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let agustin = marketplace.buscar_usuario_por_username("agustin".to_string());
+                let juan = marketplace.buscar_usuario_por_username("juan".to_string());
+
+                assert_eq!(agustin.map(|u| u.account_id), Some(vendedor));
+                assert_eq!(juan.map(|u| u.account_id), Some(comprador));
+            }
+
+            #[ink::test]
+            fn tests_buscar_usuario_por_username_no_encontrado() {
+                let (marketplace, _, _) = crear_marketplace_con_orden();
+
+                assert_eq!(
+                    marketplace.buscar_usuario_por_username("pedro".to_string()),
+                    None
+                );
+            }
+        }
+
+        mod tests_get_usuario_reporte {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_usuario_reporte_encontrado() {
+                let mut marketplace = Marketplace::new();
+
+                let account_id = AccountId::from([0xAA; 32]);
+                let _ = marketplace._registrar_usuario(
+                    account_id,
+                    "agustin".to_string(),
+                    Rol::Vendedor,
+                );
+
+                let usuario = marketplace.get_usuario_reporte(account_id);
+
+                assert_eq!(usuario.map(|u| u.username), Some("agustin".to_string()));
+            }
+
+            #[ink::test]
+            fn tests_get_usuario_reporte_no_encontrado() {
+                let marketplace = Marketplace::new();
+
+                assert_eq!(
+                    marketplace.get_usuario_reporte(AccountId::from([0xAA; 32])),
+                    None
+                );
+            }
+        }
+
+        mod tests_get_ordenes_vendedor_reporte {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_ordenes_vendedor_reporte_solo_del_vendedor() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let otro_vendedor = AccountId::from([0xCC; 32]);
+                let _ = marketplace._registrar_usuario(
+                    otro_vendedor,
+                    "pedro".to_string(),
+                    Rol::Vendedor,
+                );
+                let _ = marketplace._publicar(
+                    otro_vendedor,
+                    "Silla".to_string(),
+                    "madera".to_string(),
+                    5000,
+                    MUEBLES,
+                    4,
+                );
+                let _ = marketplace._ordenar_compra(comprador, 1, 1, 5000);
+                let _ = marketplace._ordenar_compra(comprador, 0, 2, 24000);
+
+                let pagina = marketplace.get_ordenes_vendedor_reporte(vendedor, 0, 10);
+
+                assert_eq!(pagina.total, 2);
+                assert_eq!(pagina.elementos.len(), 2);
+                assert!(pagina.elementos.iter().all(|o| o.vendedor_id == vendedor));
+                assert_eq!(pagina.elementos[1].cantidad, 2);
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_vendedor_reporte_paginado() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._ordenar_compra(comprador, 0, 2, 24000);
+                let _ = marketplace._ordenar_compra(comprador, 0, 3, 36000);
+
+                let pagina = marketplace.get_ordenes_vendedor_reporte(vendedor, 1, 1);
+
+                assert_eq!(pagina.total, 3);
+                assert_eq!(pagina.elementos.len(), 1);
+                assert_eq!(pagina.elementos[0].cantidad, 2);
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_vendedor_reporte_sin_ordenes() {
+                let marketplace = Marketplace::new();
+
+                let pagina =
+                    marketplace.get_ordenes_vendedor_reporte(AccountId::from([0xAA; 32]), 0, 10);
+
+                assert_eq!(pagina.total, 0);
+                assert!(pagina.elementos.is_empty());
+            }
+        }
+
+        mod tests_get_ventas_categorias_reporte {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_ventas_categorias_reporte_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._publicar(
+                    vendedor,
+                    "Silla".to_string(),
+                    "madera".to_string(),
+                    5000,
+                    MUEBLES,
+                    4,
+                );
+                let _ = marketplace._ordenar_compra(comprador, 0, 2, 24000);
+                let _ = marketplace._marcar_enviada(vendedor, 1);
+                let _ = marketplace._marcar_recibida(comprador, 1);
+
+                let pagina = marketplace.get_ventas_categorias_reporte(0, 10);
+
+                assert_eq!(pagina.total, CATEGORIAS_INICIALES.len() as u32);
+                assert_eq!(
+                    pagina.elementos[COMPUTACION as usize].contadores,
+                    Default::default()
+                );
+                assert_eq!(
+                    pagina.elementos[ROPA as usize],
+                    VentasCategoria {
+                        categoria: marketplace.buscar_categoria(ROPA).unwrap(),
+                        contadores: ContadoresVentas {
+                            publicaciones_activas: 1,
+                            ordenes_recibidas: 1,
+                            unidades_vendidas: 2,
+                            total_vendido: 24000,
+                        },
+                    }
+                );
+                assert_eq!(
+                    pagina.elementos[MUEBLES as usize]
+                        .contadores
+                        .publicaciones_activas,
+                    1
+                );
+            }
+
+            #[ink::test]
+            fn tests_get_ventas_categorias_reporte_publicaciones_activas() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+                for _ in 0..2 {
+                    let _ = marketplace._publicar(
+                        vendedor,
+                        "Buzo".to_string(),
+                        "lana".to_string(),
+                        30000,
+                        ROPA,
+                        5,
+                    );
+                }
+                let activas = |marketplace: &Marketplace| {
+                    marketplace.get_ventas_categorias_reporte(ROPA, 1).elementos[0]
+                        .contadores
+                        .publicaciones_activas
+                };
+                assert_eq!(activas(&marketplace), 3);
+
+                let _ = marketplace._pausar_publicacion(vendedor, 0);
+                assert_eq!(activas(&marketplace), 2);
+
+                //Dar de baja una pausada no la vuelve a descontar
+                let _ = marketplace._dar_de_baja_publicacion(owner(), 0, "spam".to_string());
+                let _ = marketplace._dar_de_baja_publicacion(owner(), 1, "spam".to_string());
+                assert_eq!(activas(&marketplace), 1);
+
+                let _ = marketplace._pausar_publicacion(vendedor, 2);
+                let _ = marketplace._reanudar_publicacion(vendedor, 2);
+                assert_eq!(activas(&marketplace), 1);
+            }
+
+            #[ink::test]
+            fn tests_get_ventas_categorias_reporte_paginado() {
+                let marketplace = Marketplace::new();
+
+                let pagina = marketplace.get_ventas_categorias_reporte(2, 10);
+
+                assert_eq!(pagina.total, 4);
+                assert_eq!(pagina.elementos.len(), 2);
+                assert_eq!(pagina.elementos[0].categoria.id_categoria, HERRAMIENTAS);
+            }
+        }
+
+        mod tests_get_resumen_ventas_reporte {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_resumen_ventas_reporte_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._publicar(
+                    vendedor,
+                    "Silla".to_string(),
+                    "madera".to_string(),
+                    5000,
+                    MUEBLES,
+                    4,
+                );
+                let _ = marketplace._pausar_publicacion(vendedor, 1);
+                let _ = marketplace._ordenar_compra(comprador, 0, 2, 24000);
+                let _ = marketplace._marcar_enviada(vendedor, 0);
+                let _ = marketplace._marcar_recibida(comprador, 0);
+
+                assert_eq!(
+                    marketplace.get_resumen_ventas_reporte(),
+                    ResumenVentas {
+                        cantidad_publicaciones: 2,
+                        cantidad_ordenes: 2,
+                        contadores: ContadoresVentas {
+                            publicaciones_activas: 1,
+                            ordenes_recibidas: 1,
+                            unidades_vendidas: 1,
+                            total_vendido: 12000,
+                        },
+                    }
+                );
+            }
+
+            #[ink::test]
+            fn tests_get_resumen_ventas_reporte_vacio() {
+                let marketplace = Marketplace::new();
+
+                assert_eq!(
+                    marketplace.get_resumen_ventas_reporte(),
+                    ResumenVentas::default()
+                );
+            }
+        }
+
+        mod tests_get_resumenes_usuarios_reporte {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_resumenes_usuarios_reporte_cuenta_ventas_recibidas() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._ordenar_compra(comprador, 0, 3, 36000);
+                let _ = marketplace._marcar_enviada(vendedor, 1);
+                let _ = marketplace._marcar_recibida(comprador, 1);
+
+                let pagina = marketplace.get_resumenes_usuarios_reporte(0, 10);
+
+                assert_eq!(pagina.total, 2);
+                assert_eq!(
+                    pagina.elementos[0],
+                    ResumenUsuario {
+                        account_id: vendedor,
+                        username: "agustin".to_string(),
+                        contadores: ContadoresUsuario {
+                            ventas: 2,
+                            ventas_recibidas: 1,
+                            total_vendido: 36000,
+                            ..Default::default()
+                        },
+                    }
+                );
+                assert_eq!(
+                    pagina.elementos[1],
+                    ResumenUsuario {
+                        account_id: comprador,
+                        username: "juan".to_string(),
+                        contadores: ContadoresUsuario {
+                            compras: 2,
+                            total_gastado: 48000,
+                            ..Default::default()
+                        },
+                    }
+                );
+            }
+
+            #[ink::test]
+            fn tests_get_resumenes_usuarios_reporte_no_cuenta_canceladas() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._ordenar_compra(comprador, 0, 2, 24000);
+                let _ = marketplace._solicitar_cancelacion(comprador, 1);
+                let _ = marketplace._aceptar_cancelacion(vendedor, 1);
+
+                let pagina = marketplace.get_resumenes_usuarios_reporte(0, 10);
+
+                //La orden 0 sigue pendiente: cuenta como compra pero no como venta
+                assert_eq!(pagina.elementos[0].contadores.ventas_recibidas, 0);
+                assert_eq!(pagina.elementos[0].contadores.total_vendido, 0);
+                assert_eq!(
+                    pagina.elementos[1].contadores,
+                    ContadoresUsuario {
+                        compras: 1,
+                        total_gastado: 12000,
+                        ..Default::default()
+                    }
+                );
+            }
+
+            #[ink::test]
+            fn tests_get_resumenes_usuarios_reporte_cuenta_cancelaciones_del_vendedor() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._ordenar_compra(comprador, 0, 2, 24000);
+                let _ = marketplace._ordenar_compra(comprador, 0, 1, 12000);
+
+                //La orden 0 se pide dos veces y se rechaza, la 1 se pide y se acepta
+                let _ = marketplace._solicitar_cancelacion(comprador, 0);
+                let _ = marketplace._rechazar_cancelacion(vendedor, 0);
+                let _ = marketplace._solicitar_cancelacion(comprador, 0);
+                let _ = marketplace._rechazar_cancelacion(vendedor, 0);
+                let _ = marketplace._solicitar_cancelacion(comprador, 1);
+                let _ = marketplace._aceptar_cancelacion(vendedor, 1);
+
+                let pagina = marketplace.get_resumenes_usuarios_reporte(0, 10);
+
+                assert_eq!(
+                    pagina.elementos[0].contadores,
+                    ContadoresUsuario {
+                        ventas: 3,
+                        ventas_canceladas: 1,
+                        ventas_con_peticion_cancelacion: 2,
+                        ..Default::default()
+                    }
+                );
+            }
+
+            #[ink::test]
+            fn tests_get_resumenes_usuarios_reporte_cuenta_bajas_como_canceladas() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
+                let _ = marketplace._dar_de_baja_publicacion(owner(), 0, "spam".to_string());
+
+                let pagina = marketplace.get_resumenes_usuarios_reporte(0, 10);
+
+                assert_eq!(pagina.elementos[0].contadores.ventas, 1);
+                assert_eq!(pagina.elementos[0].contadores.ventas_canceladas, 1);
+                assert_eq!(
+                    pagina.elementos[0]
+                        .contadores
+                        .ventas_con_peticion_cancelacion,
+                    0
+                );
+            }
+
+            #[ink::test]
+            fn tests_get_resumenes_usuarios_reporte_paginado() {
+                let mut marketplace = Marketplace::new();
+                for i in 0..MAX_LIMITE_PAGINA + 3 {
+                    let _ = marketplace._registrar_usuario(
+                        AccountId::from([i as u8; 32]),
+                        format!("usuario_{}", i),
+                        Rol::Comprador,
+                    );
+                }
+
+                let primera = marketplace.get_resumenes_usuarios_reporte(0, 500);
+                let ultima = marketplace.get_resumenes_usuarios_reporte(MAX_LIMITE_PAGINA, 500);
+
+                assert_eq!(primera.total, MAX_LIMITE_PAGINA + 3);
+                assert_eq!(primera.elementos.len(), MAX_LIMITE_PAGINA as usize);
+                assert_eq!(ultima.elementos.len(), 3);
+                assert_eq!(
+                    ultima.elementos[0].account_id,
+                    AccountId::from([MAX_LIMITE_PAGINA as u8; 32])
+                );
+            }
+
+            #[ink::test]
+            fn tests_get_resumenes_usuarios_reporte_sin_registro_fallido() {
+                let mut marketplace = Marketplace::new();
+                let _ = marketplace._registrar_usuario(
+                    AccountId::from([0xAA; 32]),
+                    "agustin".to_string(),
+                    Rol::Vendedor,
+                );
+                let _ = marketplace._registrar_usuario(
+                    AccountId::from([0xBB; 32]),
+                    "agustin".to_string(),
+                    Rol::Vendedor,
+                );
+
+                assert_eq!(marketplace.get_resumenes_usuarios_reporte(0, 10).total, 1);
+            }
+        }
+
+        mod tests_publicar {
+            use super::*;
+
+            #[ink::test]
+            fn tests_publicar_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let nombre_producto = "Remera".to_string();
+                let descripcion = "algodon".to_string();
+                let precio = 12000;
+                let categoria = ROPA;
+                let stock = 20;
+
+                assert!(marketplace
+                    ._publicar(
+                        caller,
+                        nombre_producto,
+                        descripcion,
+                        precio,
+                        categoria,
+                        stock
+                    )
+                    .is_ok());
+            }
+
+            #[ink::test]
+            fn tests_publicar_usuario_no_encontrado() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+
+                let nombre_producto = "Remera".to_string();
+                let descripcion = "algodon".to_string();
+                let precio = 12000;
+                let categoria = ROPA;
+                let stock = 20;
+
+                let result = marketplace._publicar(
+                    caller,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_publicar_usuario_no_vendedor() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Comprador;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let nombre_producto = "Remera".to_string();
+                let descripcion = "algodon".to_string();
+                let precio = 12000;
+                let categoria = ROPA;
+                let stock = 20;
+
+                let result = marketplace._publicar(
+                    caller,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsVendedor));
+            }
+
+            #[ink::test]
+            fn tests_publicar_categoria_no_existente() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let _ =
+                    marketplace._registrar_usuario(caller, "agustin".to_string(), Rol::Vendedor);
+
+                let result = marketplace._publicar(
+                    caller,
+                    "Heladera".to_string(),
+                    "no frost".to_string(),
+                    500000,
+                    4,
+                    2,
+                );
+
+                assert_eq!(result, Err(ErrorSistema::CategoriaNoExistente));
+                assert_eq!(marketplace.cantidad_publicaciones, 0);
+            }
+
+            #[ink::test]
+            fn tests_publicar_en_categoria_creada() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let _ =
+                    marketplace._registrar_usuario(caller, "agustin".to_string(), Rol::Vendedor);
+                let categoria = marketplace
+                    ._crear_categoria(owner(), "Electrodomesticos".to_string(), None)
+                    .unwrap();
+
+                let result = marketplace._publicar(
+                    caller,
+                    "Heladera".to_string(),
+                    "no frost".to_string(),
+                    500000,
+                    categoria.id_categoria,
+                    2,
+                );
+
+                assert_eq!(result.map(|p| p.id_categoria), Ok(4));
+            }
+        }
+
+        mod tests_get_publicaciones_vendedor {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_publicaciones_vendedor_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let mut nombre_producto = "Remera".to_string();
+                let mut descripcion = "algodon".to_string();
+                let mut precio = 12000;
+                let mut categoria = ROPA;
+                let mut stock = 20;
+
+                let _ = marketplace._publicar(
+                    caller,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                nombre_producto = "Pantalon".to_string();
+                descripcion = "Jean".to_string();
+                precio = 20000;
+                categoria = ROPA;
+                stock = 5;
+
+                let _ = marketplace._publicar(
+                    caller,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                assert!(marketplace
+                    ._get_publicaciones_vendedor(caller, caller)
+                    .is_ok());
+
+                if let Ok(vec_publicaciones) =
+                    marketplace._get_publicaciones_vendedor(caller, caller)
+                {
+                    assert_eq!(vec_publicaciones.len(), 2);
+                }
+            }
+
+            #[ink::test]
+            fn tests_get_publicaciones_vendedor_usuario_no_encontrado() {
+                let marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+
+                let result = marketplace._get_publicaciones_vendedor(caller, caller);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_get_publicaciones_vendedor_usuario_no_vendedor() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Comprador;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let result = marketplace._get_publicaciones_vendedor(caller, caller);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsVendedor));
+            }
+        }
+
+        mod tests_get_publicaciones_de_vendedor {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_publicaciones_de_vendedor_oculta_pausadas() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._publicar(
+                    vendedor,
+                    "Pantalon".to_string(),
+                    "Jean".to_string(),
+                    20000,
+                    ROPA,
+                    5,
+                );
+                let _ = marketplace._pausar_publicacion(vendedor, 0_u32);
+
+                let publicaciones = marketplace
+                    ._get_publicaciones_vendedor(comprador, vendedor)
+                    .unwrap();
+
+                assert_eq!(publicaciones.len(), 1);
+                assert_eq!(publicaciones[0].nombre_producto, "Pantalon");
+            }
+
+            #[ink::test]
+            fn tests_get_publicaciones_de_vendedor_no_existente() {
+                let (marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result =
+                    marketplace._get_publicaciones_vendedor(comprador, AccountId::from([0xEE; 32]));
+
+                assert_eq!(result, Err(ErrorSistema::VendedorNoExistente));
+            }
+
+            #[ink::test]
+            fn tests_get_publicaciones_de_vendedor_no_es_vendedor() {
+                let (marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let result = marketplace._get_publicaciones_vendedor(vendedor, comprador);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsVendedor));
+            }
+
+            #[ink::test]
+            fn tests_get_publicaciones_de_vendedor_usuario_no_registrado() {
+                let (marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result =
+                    marketplace._get_publicaciones_vendedor(AccountId::from([0xEE; 32]), vendedor);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+        }
+
+        mod tests_get_publicaciones {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_publicaciones_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let caller1 = AccountId::from([0xAA; 32]);
+                let username1 = "agustin".to_string();
+                let rol1 = Rol::Ambos;
+
+                let caller2 = AccountId::from([0xAA; 32]);
+                let username2 = "agustin".to_string();
+                let rol2 = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller1, username1, rol1);
+                let _ = marketplace._registrar_usuario(caller2, username2, rol2);
+
+                let mut nombre_producto = "Remera".to_string();
+                let mut descripcion = "algodon".to_string();
+                let mut precio = 12000;
+                let mut categoria = ROPA;
+                let mut stock = 20;
+
+                let _ = marketplace._publicar(
+                    caller1,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                nombre_producto = "Pantalon".to_string();
+                descripcion = "Jean".to_string();
+                precio = 20000;
+                categoria = ROPA;
+                stock = 5;
+
+                let _ = marketplace._publicar(
+                    caller1,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                nombre_producto = "Notebook".to_string();
+                descripcion = "Ryzen 7".to_string();
+                precio = 200000;
+                categoria = COMPUTACION;
+                stock = 10;
+
+                let _ = marketplace._publicar(
+                    caller2,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                assert!(marketplace._get_publicaciones(caller1).is_ok());
+
+                if let Ok(vec_publicaciones) = marketplace._get_publicaciones(caller1) {
+                    assert_eq!(vec_publicaciones.len(), 3);
+                }
+            }
+
+            #[ink::test]
+            fn tests_get_publicaciones_usuario_no_encontrado() {
+                let marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+
+                let result = marketplace._get_publicaciones(caller);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+        }
+
+        mod tests_buscar_publicaciones {
+            use super::*;
+
+            //Dos vendedores con publicaciones de distintas categorias, precios y stock.
+            //agustin: 0 Remera 12000 Ropa, 1 Notebook 200000 Computacion,
+            //2 Buzo 30000 Ropa sin stock. pedro: 3 Pantalon 20000 Ropa,
+            //4 Silla 5000 Muebles pausada
+            fn crear_marketplace_con_catalogo() -> (Marketplace, AccountId, AccountId, AccountId) {
+                let mut marketplace = Marketplace::new();
+
+                let agustin = AccountId::from([0xAA; 32]);
+                let pedro = AccountId::from([0xCC; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                let _ =
+                    marketplace._registrar_usuario(agustin, "agustin".to_string(), Rol::Vendedor);
+                let _ = marketplace._registrar_usuario(pedro, "pedro".to_string(), Rol::Ambos);
+                let _ =
+                    marketplace._registrar_usuario(comprador, "juan".to_string(), Rol::Comprador);
+
+                for (vendedor, nombre, precio, categoria, stock) in [
+                    (agustin, "Remera", 12000, ROPA, 20),
+                    (agustin, "Notebook", 200000, COMPUTACION, 2),
+                    (agustin, "Buzo", 30000, ROPA, 0),
+                    (pedro, "Pantalon", 20000, ROPA, 5),
+                    (pedro, "Silla", 5000, MUEBLES, 4),
+                ] {
+                    let _ = marketplace._publicar(
+                        vendedor,
+                        nombre.to_string(),
+                        "descripcion".to_string(),
+                        precio,
+                        categoria,
+                        stock,
+                    );
+                }
+                let _ = marketplace._pausar_publicacion(pedro, 4);
+
+                (marketplace, agustin, pedro, comprador)
+            }
+
+            fn ids(pagina: Pagina<Publicacion>) -> Vec<u64> {
+                pagina.elementos.iter().map(|p| p.id_publicacion).collect()
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_sin_filtros() {
+                let (marketplace, _, _, comprador) = crear_marketplace_con_catalogo();
+
+                let result = marketplace._buscar_publicaciones(
+                    comprador,
+                    FiltroPublicaciones::default(),
+                    0,
+                    MAX_LIMITE_PAGINA,
+                );
+
+                assert_eq!(result.map(ids), Ok(vec![0, 1, 2, 3]));
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_por_categoria() {
+                let (marketplace, _, _, comprador) = crear_marketplace_con_catalogo();
+
+                let filtro = FiltroPublicaciones {
+                    id_categoria: Some(ROPA),
+                    ..Default::default()
+                };
+                let result =
+                    marketplace._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA);
+
+                assert_eq!(result.map(ids), Ok(vec![0, 2, 3]));
+                assert_eq!(
+                    marketplace.publicaciones_categoria.get(ROPA),
+                    Some(vec![0, 2, 3])
+                );
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_incluye_subcategorias() {
+                let (mut marketplace, agustin, pedro, comprador) = crear_marketplace_con_catalogo();
+                let notebooks = marketplace
+                    ._crear_categoria(owner(), "Notebooks".to_string(), Some(COMPUTACION))
+                    .unwrap()
+                    .id_categoria;
+                let gamer = marketplace
+                    ._crear_categoria(owner(), "Gamer".to_string(), Some(notebooks))
+                    .unwrap()
+                    .id_categoria;
+                for (vendedor, nombre, categoria) in
+                    [(pedro, "Thinkpad", notebooks), (agustin, "Legion", gamer)]
+                {
+                    let _ = marketplace._publicar(
+                        vendedor,
+                        nombre.to_string(),
+                        "descripcion".to_string(),
+                        300000,
+                        categoria,
+                        1,
+                    );
+                }
+
+                let buscar = |id_categoria| {
+                    let filtro = FiltroPublicaciones {
+                        id_categoria: Some(id_categoria),
+                        ..Default::default()
+                    };
+                    marketplace
+                        ._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA)
+                        .map(ids)
+                };
+
+                assert_eq!(buscar(COMPUTACION), Ok(vec![1, 5, 6]));
+                assert_eq!(buscar(notebooks), Ok(vec![5, 6]));
+                assert_eq!(buscar(gamer), Ok(vec![6]));
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_por_rango_de_precio_y_stock() {
+                let (marketplace, _, _, comprador) = crear_marketplace_con_catalogo();
+
+                let filtro = FiltroPublicaciones {
+                    precio_min: Some(12000),
+                    precio_max: Some(30000),
+                    solo_con_stock: true,
+                    ..Default::default()
+                };
+                let result =
+                    marketplace._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA);
+
+                assert_eq!(result.map(ids), Ok(vec![0, 3]));
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_por_vendedor_y_categoria() {
+                let (marketplace, agustin, pedro, comprador) = crear_marketplace_con_catalogo();
+
+                let filtro = FiltroPublicaciones {
+                    id_categoria: Some(ROPA),
+                    vendedor_id: Some(agustin),
+                    ..Default::default()
+                };
+                let result =
+                    marketplace._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA);
+                assert_eq!(result.map(ids), Ok(vec![0, 2]));
+
+                //Las publicaciones pausadas no aparecen
+                let filtro = FiltroPublicaciones {
+                    id_categoria: Some(MUEBLES),
+                    vendedor_id: Some(pedro),
+                    ..Default::default()
+                };
+                let result =
+                    marketplace._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA);
+                assert_eq!(result.map(ids), Ok(Vec::new()));
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_ordenadas_por_precio() {
+                let (marketplace, _, _, comprador) = crear_marketplace_con_catalogo();
+
+                let filtro = FiltroPublicaciones {
+                    ordenar_por: Some(OrdenPublicaciones::PrecioAscendente),
+                    ..Default::default()
+                };
+                let result =
+                    marketplace._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA);
+                assert_eq!(result.map(ids), Ok(vec![0, 3, 2, 1]));
+
+                let filtro = FiltroPublicaciones {
+                    id_categoria: Some(ROPA),
+                    ordenar_por: Some(OrdenPublicaciones::PrecioDescendente),
+                    ..Default::default()
+                };
+                let result =
+                    marketplace._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA);
+                assert_eq!(result.map(ids), Ok(vec![2, 3, 0]));
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_mas_recientes() {
+                let (mut marketplace, agustin, _, comprador) = crear_marketplace_con_catalogo();
+
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+                let _ = marketplace._publicar(
+                    agustin,
+                    "Taladro".to_string(),
+                    "descripcion".to_string(),
+                    8000,
+                    HERRAMIENTAS,
+                    3,
+                );
+                assert_eq!(marketplace.publicaciones.get(5).unwrap().bloque_creacion, 1);
+
+                let filtro = FiltroPublicaciones {
+                    ordenar_por: Some(OrdenPublicaciones::MasRecientes),
+                    ..Default::default()
+                };
+                let result =
+                    marketplace._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA);
+
+                assert_eq!(result.map(ids), Ok(vec![5, 3, 2, 1, 0]));
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_mas_vendidas() {
+                let (mut marketplace, _, pedro, comprador) = crear_marketplace_con_catalogo();
+
+                let _ = marketplace._ordenar_compra(comprador, 3_u32, 2, 40000);
+                let _ = marketplace._ordenar_compra(comprador, 3_u32, 1, 20000);
+                let _ = marketplace._ordenar_compra(comprador, 1_u32, 1, 200000);
+                //La orden cancelada no cuenta como venta
+                let _ = marketplace._ordenar_compra(comprador, 0_u32, 5, 60000);
+                let _ = marketplace._solicitar_cancelacion(comprador, 3_u32);
+                let _ = marketplace._aceptar_cancelacion(AccountId::from([0xAA; 32]), 3_u32);
+
+                assert_eq!(
+                    marketplace.publicaciones.get(3).unwrap().unidades_vendidas,
+                    3
+                );
+                assert_eq!(
+                    marketplace.publicaciones.get(0).unwrap().unidades_vendidas,
+                    0
+                );
+
+                let filtro = FiltroPublicaciones {
+                    ordenar_por: Some(OrdenPublicaciones::MasVendidas),
+                    ..Default::default()
+                };
+                let result =
+                    marketplace._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA);
+                assert_eq!(result.map(ids), Ok(vec![3, 1, 0, 2]));
+
+                let filtro = FiltroPublicaciones {
+                    vendedor_id: Some(pedro),
+                    ordenar_por: Some(OrdenPublicaciones::MasVendidas),
+                    ..Default::default()
+                };
+                let result =
+                    marketplace._buscar_publicaciones(comprador, filtro, 0, MAX_LIMITE_PAGINA);
+                assert_eq!(result.map(ids), Ok(vec![3]));
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_usuario_no_registrado() {
+                let (marketplace, _, _, _) = crear_marketplace_con_catalogo();
+
+                let result = marketplace._buscar_publicaciones(
+                    AccountId::from([0xEE; 32]),
+                    Default::default(),
+                    0,
+                    MAX_LIMITE_PAGINA,
+                );
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_paginado() {
+                let (marketplace, _, _, comprador) = crear_marketplace_con_catalogo();
+
+                let filtro = FiltroPublicaciones {
+                    ordenar_por: Some(OrdenPublicaciones::PrecioAscendente),
+                    ..Default::default()
+                };
+                let pagina = marketplace
+                    ._buscar_publicaciones(comprador, filtro.clone(), 1, 2)
+                    .unwrap();
+                assert_eq!(pagina.total, 4);
+                assert_eq!(ids(pagina), vec![3, 2]);
+
+                let pagina = marketplace
+                    ._buscar_publicaciones(comprador, filtro, 3, 10)
+                    .unwrap();
+                assert_eq!(pagina.total, 4);
+                assert_eq!(ids(pagina), vec![1]);
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_limite_maximo() {
+                let (mut marketplace, agustin, _, comprador) = crear_marketplace_con_catalogo();
+                for _ in 0..MAX_LIMITE_PAGINA {
+                    let _ = marketplace._publicar(
+                        agustin,
+                        "Taladro".to_string(),
+                        "descripcion".to_string(),
+                        8000,
+                        HERRAMIENTAS,
+                        3,
+                    );
+                }
+
+                let pagina = marketplace
+                    ._buscar_publicaciones(comprador, Default::default(), 0, u32::MAX)
+                    .unwrap();
+
+                //Sin orden la busqueda se corta despues de la pagina
+                assert_eq!(pagina.total, MAX_LIMITE_PAGINA + 1);
+                assert_eq!(pagina.elementos.len(), MAX_LIMITE_PAGINA as usize);
+
+                let filtro = FiltroPublicaciones {
+                    ordenar_por: Some(OrdenPublicaciones::PrecioAscendente),
+                    ..Default::default()
+                };
+                let pagina = marketplace
+                    ._buscar_publicaciones(comprador, filtro, 0, u32::MAX)
+                    .unwrap();
+
+                assert_eq!(pagina.total, MAX_LIMITE_PAGINA + 4);
+                assert_eq!(pagina.elementos.len(), MAX_LIMITE_PAGINA as usize);
+            }
+
+            #[ink::test]
+            fn tests_buscar_publicaciones_sin_orden_corta_en_la_pagina() {
+                let (marketplace, _, _, comprador) = crear_marketplace_con_catalogo();
+
+                let buscar = |offset, limit| {
+                    marketplace
+                        ._buscar_publicaciones(comprador, Default::default(), offset, limit)
+                        .unwrap()
                 };
 
-                assert_eq!(usuario.es_vendedor().is_ok(), false);
+                //Los resultados son 0, 1, 2 y 3
+                let pagina = buscar(0, 2);
+                assert_eq!(pagina.total, 3);
+                assert_eq!(ids(pagina), vec![0, 1]);
+
+                let pagina = buscar(2, 2);
+                assert_eq!(pagina.total, 4);
+                assert_eq!(ids(pagina), vec![2, 3]);
+
+                let pagina = buscar(3, 10);
+                assert_eq!(pagina.total, 4);
+                assert_eq!(ids(pagina), vec![3]);
+
+                let pagina = buscar(10, 2);
+                assert_eq!(pagina.total, 4);
+                assert!(pagina.elementos.is_empty());
+            }
+        }
+
+        mod tests_editar_publicacion {
+            use super::*;
+
+            #[ink::test]
+            fn tests_editar_publicacion_parcial() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result =
+                    marketplace._editar_publicacion(vendedor, 0_u32, None, None, Some(15000), None);
+
+                assert!(result.is_ok());
+                let publicacion = marketplace.publicaciones.get(0).unwrap();
+                assert_eq!(publicacion.precio, 15000);
+                assert_eq!(publicacion.nombre_producto, "Remera");
+                assert_eq!(publicacion.descripcion, "algodon");
+                assert_eq!(publicacion.stock, 19);
+            }
+
+            #[ink::test]
+            fn tests_editar_publicacion_todos_los_campos() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._editar_publicacion(
+                    vendedor,
+                    0_u32,
+                    Some("Remera lisa".to_string()),
+                    Some("algodon peinado".to_string()),
+                    Some(15000),
+                    Some(3),
+                );
+
+                let publicacion = marketplace.publicaciones.get(0).unwrap();
+                assert_eq!(result, Ok(publicacion.clone()));
+                assert_eq!(publicacion.nombre_producto, "Remera lisa");
+                assert_eq!(publicacion.descripcion, "algodon peinado");
+                assert_eq!(publicacion.precio, 15000);
+                assert_eq!(publicacion.stock, 3);
+            }
+
+            #[ink::test]
+            fn tests_editar_publicacion_no_modifica_ordenes_existentes() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let _ =
+                    marketplace._editar_publicacion(vendedor, 0_u32, None, None, Some(15000), None);
+
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().precio_unitario,
+                    12000
+                );
+            }
+
+            #[ink::test]
+            fn tests_editar_publicacion_no_es_vendedor_de_la_publicacion() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
+
+                let otro_vendedor = AccountId::from([0xCC; 32]);
+                let _ = marketplace._registrar_usuario(
+                    otro_vendedor,
+                    "pedro".to_string(),
+                    Rol::Vendedor,
+                );
+
+                let result = marketplace._editar_publicacion(
+                    otro_vendedor,
+                    0_u32,
+                    None,
+                    None,
+                    Some(1),
+                    None,
+                );
+
+                assert_eq!(result, Err(ErrorSistema::NoEsVendedorDeLaPublicacion));
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().precio, 12000);
+            }
+
+            #[ink::test]
+            fn tests_editar_publicacion_usuario_no_vendedor() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result =
+                    marketplace._editar_publicacion(comprador, 0_u32, None, None, Some(1), None);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsVendedor));
+            }
+
+            #[ink::test]
+            fn tests_editar_publicacion_no_existente() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result =
+                    marketplace._editar_publicacion(vendedor, 1_u32, None, None, Some(1), None);
+
+                assert_eq!(result, Err(ErrorSistema::PublicacionNoExistente));
+            }
+        }
+
+        mod tests_reponer_stock {
+            use super::*;
+
+            #[ink::test]
+            fn tests_reponer_stock_correcto() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._reponer_stock(vendedor, 0_u32, 10);
+
+                assert!(result.is_ok());
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 29);
+            }
+
+            #[ink::test]
+            fn tests_reponer_stock_cantidad_cero() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._reponer_stock(vendedor, 0_u32, 0);
+
+                assert_eq!(result, Err(ErrorSistema::CantidadInvalida));
+            }
+
+            #[ink::test]
+            fn tests_reponer_stock_overflow() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._reponer_stock(vendedor, 0_u32, u64::MAX);
+
+                assert_eq!(result, Err(ErrorSistema::OverflowStock));
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 19);
+            }
+
+            #[ink::test]
+            fn tests_reponer_stock_no_es_vendedor_de_la_publicacion() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
+
+                let otro_vendedor = AccountId::from([0xCC; 32]);
+                let _ = marketplace._registrar_usuario(
+                    otro_vendedor,
+                    "pedro".to_string(),
+                    Rol::Vendedor,
+                );
+
+                let result = marketplace._reponer_stock(otro_vendedor, 0_u32, 10);
+
+                assert_eq!(result, Err(ErrorSistema::NoEsVendedorDeLaPublicacion));
+            }
+        }
+
+        mod tests_pausar_publicacion {
+            use super::*;
+
+            #[ink::test]
+            fn tests_pausar_publicacion_correcto() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._pausar_publicacion(vendedor, 0_u32);
+
+                assert!(result.is_ok());
+                assert!(!marketplace.publicaciones.get(0).unwrap().activa);
+            }
+
+            #[ink::test]
+            fn tests_pausar_publicacion_oculta_y_rechaza_ordenes() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._publicar(
+                    vendedor,
+                    "Pantalon".to_string(),
+                    "Jean".to_string(),
+                    20000,
+                    ROPA,
+                    5,
+                );
+                let _ = marketplace._pausar_publicacion(vendedor, 0_u32);
+
+                let publicaciones = marketplace._get_publicaciones(comprador).unwrap();
+                assert_eq!(publicaciones.len(), 1);
+                assert_eq!(publicaciones[0].id_publicacion, 1);
+
+                let pagina = marketplace
+                    ._get_publicaciones_paginado(comprador, 0, 10)
+                    .unwrap();
+                assert_eq!(pagina.elementos.len(), 1);
+
+                //El vendedor sigue viendo sus publicaciones pausadas
+                let propias = marketplace
+                    ._get_publicaciones_vendedor(vendedor, vendedor)
+                    .unwrap();
+                assert_eq!(propias.len(), 2);
+
+                let result = marketplace._ordenar_compra(comprador, 0_u32, 1, 12000);
+                assert_eq!(result, Err(ErrorSistema::PublicacionPausada));
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 19);
+            }
+
+            #[ink::test]
+            fn tests_pausar_publicacion_ya_pausada() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let _ = marketplace._pausar_publicacion(vendedor, 0_u32);
+                let result = marketplace._pausar_publicacion(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::PublicacionPausada));
+            }
+
+            #[ink::test]
+            fn tests_pausar_publicacion_no_es_vendedor_de_la_publicacion() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
+
+                let otro_vendedor = AccountId::from([0xCC; 32]);
+                let _ = marketplace._registrar_usuario(
+                    otro_vendedor,
+                    "pedro".to_string(),
+                    Rol::Vendedor,
+                );
+
+                let result = marketplace._pausar_publicacion(otro_vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::NoEsVendedorDeLaPublicacion));
+                assert!(marketplace.publicaciones.get(0).unwrap().activa);
+            }
+        }
+
+        mod tests_reanudar_publicacion {
+            use super::*;
+
+            #[ink::test]
+            fn tests_reanudar_publicacion_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._pausar_publicacion(vendedor, 0_u32);
+                let result = marketplace._reanudar_publicacion(vendedor, 0_u32);
+
+                assert!(result.is_ok());
+                assert!(marketplace.publicaciones.get(0).unwrap().activa);
+                assert!(marketplace
+                    ._ordenar_compra(comprador, 0_u32, 1, 12000)
+                    .is_ok());
+            }
+
+            #[ink::test]
+            fn tests_reanudar_publicacion_ya_activa() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._reanudar_publicacion(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::PublicacionYaActiva));
+            }
+        }
+
+        mod tests_ordenar_compra {
+            use super::*;
+
+            #[ink::test]
+            fn tests_ordenar_compra_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let nombre_producto = "Remera".to_string();
+                let descripcion = "algodon".to_string();
+                let precio = 12000;
+                let categoria = ROPA;
+                let stock = 20;
+
+                let _ = marketplace._publicar(
+                    caller,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                let orden = marketplace._ordenar_compra(caller, 0_u32, 1, 12000);
+                assert!(orden.is_ok());
+                assert!(marketplace.publicaciones.get(0).unwrap().stock == 19);
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_retiene_fondos() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let _ = marketplace._publicar(
+                    caller,
+                    "Remera".to_string(),
+                    "algodon".to_string(),
+                    12000,
+                    ROPA,
+                    20,
+                );
+
+                let _ = marketplace._ordenar_compra(caller, 0_u32, 1, 12000);
+
+                assert_eq!(marketplace.fondos_retenidos.get(0_u32), Some(12000));
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_monto_incorrecto() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let _ = marketplace._publicar(
+                    caller,
+                    "Remera".to_string(),
+                    "algodon".to_string(),
+                    12000,
+                    ROPA,
+                    20,
+                );
+
+                let menos = marketplace._ordenar_compra(caller, 0_u32, 1, 11999);
+                let mas = marketplace._ordenar_compra(caller, 0_u32, 1, 12001);
+
+                assert_eq!(menos, Err(ErrorSistema::MontoIncorrecto));
+                assert_eq!(mas, Err(ErrorSistema::MontoIncorrecto));
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 20);
+                assert_eq!(marketplace.cantidad_ordenes, 0);
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_varias_unidades() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let _ = marketplace._publicar(
+                    caller,
+                    "Remera".to_string(),
+                    "algodon".to_string(),
+                    12000,
+                    ROPA,
+                    20,
+                );
+
+                let orden = marketplace._ordenar_compra(caller, 0_u32, 5, 60000);
+
+                assert!(orden.is_ok());
+                assert_eq!(marketplace.cantidad_ordenes, 1);
+                assert_eq!(marketplace.ordenes_compra.get(0).unwrap().cantidad, 5);
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 15);
+                assert_eq!(marketplace.fondos_retenidos.get(0_u32), Some(60000));
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_todo_el_stock() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let _ = marketplace._publicar(
+                    caller,
+                    "Pantalon".to_string(),
+                    "Jean".to_string(),
+                    20000,
+                    ROPA,
+                    5,
+                );
+
+                assert!(marketplace
+                    ._ordenar_compra(caller, 0_u32, 5, 100000)
+                    .is_ok());
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 0);
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_cantidad_mayor_al_stock() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let _ = marketplace._publicar(
+                    caller,
+                    "Pantalon".to_string(),
+                    "Jean".to_string(),
+                    20000,
+                    ROPA,
+                    5,
+                );
+
+                let result = marketplace._ordenar_compra(caller, 0_u32, 6, 120000);
+
+                assert_eq!(result, Err(ErrorSistema::PublicacionSinStock));
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 5);
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_cantidad_cero() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let _ = marketplace._publicar(
+                    caller,
+                    "Remera".to_string(),
+                    "algodon".to_string(),
+                    12000,
+                    ROPA,
+                    20,
+                );
+
+                let result = marketplace._ordenar_compra(caller, 0_u32, 0, 0);
+
+                assert_eq!(result, Err(ErrorSistema::CantidadInvalida));
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_overflow_precio_total() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let _ = marketplace._publicar(
+                    caller,
+                    "Notebook".to_string(),
+                    "Ryzen 7".to_string(),
+                    u64::MAX,
+                    COMPUTACION,
+                    10,
+                );
+
+                let result = marketplace._ordenar_compra(caller, 0_u32, 2, 0);
+
+                assert_eq!(result, Err(ErrorSistema::OverflowPrecioTotal));
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 10);
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_usuario_no_encontrado() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+
+                let result = marketplace._ordenar_compra(caller, 0_u32, 1, 0);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_usuario_no_comprador() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Vendedor;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let result = marketplace._ordenar_compra(caller, 0_u32, 1, 0);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsComprador));
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_publicacion_no_existente() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let nombre_producto = "Remera".to_string();
+                let descripcion = "algodon".to_string();
+                let precio = 12000;
+                let categoria = ROPA;
+                let stock = 20;
+
+                let _ = marketplace._publicar(
+                    caller,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                let result = marketplace._ordenar_compra(caller, 1_u32, 1, 12000);
+
+                assert_eq!(result, Err(ErrorSistema::PublicacionNoExistente));
+            }
+
+            #[ink::test]
+            fn tests_ordenar_compra_publicacion_sin_stock() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let nombre_producto = "Remera".to_string();
+                let descripcion = "algodon".to_string();
+                let precio = 12000;
+                let categoria = ROPA;
+                let stock = 0;
+
+                let _ = marketplace._publicar(
+                    caller,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                let result = marketplace._ordenar_compra(caller, 0_u32, 1, 12000);
+
+                assert_eq!(result, Err(ErrorSistema::PublicacionSinStock));
+            }
+        }
+
+        mod tests_get_ordenes_comprador {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_ordenes_comprador_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let mut nombre_producto = "Remera".to_string();
+                let mut descripcion = "algodon".to_string();
+                let mut precio = 12000;
+                let mut categoria = ROPA;
+                let mut stock = 20;
+
+                let _ = marketplace._publicar(
+                    caller,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                let _ = marketplace._ordenar_compra(caller, 0_u32, 1, 12000);
+
+                nombre_producto = "Pantalon".to_string();
+                descripcion = "Jean".to_string();
+                precio = 20000;
+                categoria = ROPA;
+                stock = 5;
+
+                let _ = marketplace._publicar(
+                    caller,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                let _ = marketplace._ordenar_compra(caller, 1_u32, 1, 20000);
+
+                assert!(marketplace._get_ordenes_comprador(caller).is_ok());
+
+                if let Ok(vec_ordenes) = marketplace._get_ordenes_comprador(caller) {
+                    assert_eq!(vec_ordenes.len(), 2);
+                }
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_comprador_usuario_no_encontrado() {
+                let marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+
+                let result = marketplace._get_ordenes_comprador(caller);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_comprador_usuario_no_comprador() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Vendedor;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let result = marketplace._get_ordenes_comprador(caller);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsComprador));
+            }
+        }
+
+        mod tests_get_ordenes_vendedor {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_ordenes_vendedor_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                //Orden sobre la publicacion de otro vendedor que no debe aparecer
+                let otro_vendedor = AccountId::from([0xCC; 32]);
+                let _ = marketplace._registrar_usuario(
+                    otro_vendedor,
+                    "pedro".to_string(),
+                    Rol::Vendedor,
+                );
+                let _ = marketplace._publicar(
+                    otro_vendedor,
+                    "Silla".to_string(),
+                    "madera".to_string(),
+                    5000,
+                    MUEBLES,
+                    2,
+                );
+                let _ = marketplace._ordenar_compra(comprador, 1_u32, 1, 5000);
+                let _ = marketplace._ordenar_compra(comprador, 0_u32, 2, 24000);
+
+                let ordenes = marketplace._get_ordenes_vendedor(vendedor, None).unwrap();
+
+                assert_eq!(ordenes.len(), 2);
+                assert!(ordenes.iter().all(|o| o.vendedor_id == vendedor));
+                assert_eq!(marketplace.ids_ordenes_vendedor(vendedor), vec![0, 2]);
+                assert_eq!(marketplace.ids_ordenes_vendedor(otro_vendedor), vec![1]);
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_vendedor_filtrado_por_estado() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._ordenar_compra(comprador, 0_u32, 1, 12000);
+                let _ = marketplace._marcar_enviada(vendedor, 1_u32);
+
+                let pendientes = marketplace
+                    ._get_ordenes_vendedor(vendedor, Some(Estado::Pendiente))
+                    .unwrap();
+                let enviadas = marketplace
+                    ._get_ordenes_vendedor(vendedor, Some(Estado::Enviada))
+                    .unwrap();
+                let recibidas = marketplace
+                    ._get_ordenes_vendedor(vendedor, Some(Estado::Recibida))
+                    .unwrap();
+
+                assert_eq!(pendientes.len(), 1);
+                assert_eq!(pendientes[0].estado, Estado::Pendiente);
+                assert_eq!(enviadas.len(), 1);
+                assert_eq!(enviadas[0].estado, Estado::Enviada);
+                assert!(recibidas.is_empty());
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_vendedor_sin_ordenes() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let _ =
+                    marketplace._registrar_usuario(vendedor, "agustin".to_string(), Rol::Vendedor);
+
+                let result = marketplace._get_ordenes_vendedor(vendedor, None);
+
+                assert_eq!(result, Ok(Vec::new()));
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_vendedor_usuario_no_encontrado() {
+                let marketplace = Marketplace::new();
+
+                let result = marketplace._get_ordenes_vendedor(AccountId::from([0xAA; 32]), None);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_vendedor_usuario_no_vendedor() {
+                let (marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result = marketplace._get_ordenes_vendedor(comprador, None);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsVendedor));
+            }
+        }
+
+        mod tests_get_ordenes {
+            use super::*;
+
+            #[ink::test]
+            fn tests_get_ordenes_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let caller1 = AccountId::from([0xAA; 32]);
+                let username1 = "agustin".to_string();
+                let rol1 = Rol::Ambos;
+
+                let caller2 = AccountId::from([0xBB; 32]);
+                let username2 = "juan".to_string();
+                let rol2 = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller1, username1, rol1);
+                let _ = marketplace._registrar_usuario(caller2, username2, rol2);
+
+                let mut nombre_producto = "Remera".to_string();
+                let mut descripcion = "algodon".to_string();
+                let mut precio = 12000;
+                let mut categoria = ROPA;
+                let mut stock = 20;
+
+                let _ = marketplace._publicar(
+                    caller1,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                let _ = marketplace._ordenar_compra(caller2, 0_u32, 1, 12000);
+
+                nombre_producto = "Pantalon".to_string();
+                descripcion = "Jean".to_string();
+                precio = 20000;
+                categoria = ROPA;
+                stock = 5;
+
+                let _ = marketplace._publicar(
+                    caller1,
+                    nombre_producto,
+                    descripcion,
+                    precio,
+                    categoria,
+                    stock,
+                );
+
+                let _ = marketplace._ordenar_compra(caller2, 1_u32, 1, 20000);
+
+                assert!(marketplace._get_ordenes(caller1).is_ok());
+
+                if let Ok(vec_ordenes) = marketplace._get_ordenes(caller1) {
+                    assert_eq!(vec_ordenes.len(), 2);
+                }
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_usuario_no_encontrado() {
+                let marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+
+                let result = marketplace._get_ordenes(caller);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_sin_ordenes() {
+                let mut marketplace = Marketplace::new();
+
+                let caller = AccountId::from([0xAA; 32]);
+                let username = "agustin".to_string();
+                let rol = Rol::Ambos;
+
+                let _ = marketplace._registrar_usuario(caller, username, rol);
+
+                let result = marketplace._get_ordenes(caller);
+
+                assert!(result.is_ok());
+                if let Ok(vec_ordenes) = result {
+                    assert_eq!(vec_ordenes.len(), 0);
+                }
+            }
+        }
+
+        //Registra un vendedor y un comprador, publica un producto y crea una orden
+        //de compra sobre el. Retorna (marketplace, vendedor, comprador)
+        fn crear_marketplace_con_orden() -> (Marketplace, AccountId, AccountId) {
+            let mut marketplace = Marketplace::new();
+
+            let vendedor = AccountId::from([0xAA; 32]);
+            let comprador = AccountId::from([0xBB; 32]);
+
+            let _ = marketplace._registrar_usuario(vendedor, "agustin".to_string(), Rol::Vendedor);
+            let _ = marketplace._registrar_usuario(comprador, "juan".to_string(), Rol::Comprador);
+
+            let _ = marketplace._publicar(
+                vendedor,
+                "Remera".to_string(),
+                "algodon".to_string(),
+                12000,
+                ROPA,
+                20,
+            );
+
+            let _ = marketplace._ordenar_compra(comprador, 0_u32, 1, 12000);
+
+            (marketplace, vendedor, comprador)
+        }
+
+        //Retorna el balance de una cuenta en el entorno de pruebas
+        fn balance_de(account_id: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account_id)
+                .unwrap_or_default()
+        }
+
+        //Cuenta que despliega el contrato en el entorno de pruebas
+        fn owner() -> AccountId {
+            ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice
+        }
+
+        //Retorna la cuenta del contrato en el entorno de pruebas
+        fn contrato() -> AccountId {
+            ink::env::test::callee::<ink::env::DefaultEnvironment>()
+        }
+
+        mod tests_estado_transicionar {
+            use super::*;
+
+            #[test]
+            fn tests_transicionar_pendiente_a_enviada() {
+                assert_eq!(
+                    Estado::Pendiente.transicionar(Estado::Enviada),
+                    Ok(Estado::Enviada)
+                );
+            }
+
+            #[test]
+            fn tests_transicionar_enviada_a_recibida() {
+                assert_eq!(
+                    Estado::Enviada.transicionar(Estado::Recibida),
+                    Ok(Estado::Recibida)
+                );
+            }
+
+            #[test]
+            fn tests_transicionar_pendiente_a_cancelada() {
+                assert_eq!(
+                    Estado::Pendiente.transicionar(Estado::Cancelada),
+                    Ok(Estado::Cancelada)
+                );
+            }
+
+            #[test]
+            fn tests_transicionar_enviada_a_cancelada_error() {
+                assert_eq!(
+                    Estado::Enviada.transicionar(Estado::Cancelada),
+                    Err(ErrorSistema::OrdenNoPendiente)
+                );
+            }
+
+            #[test]
+            fn tests_transicionar_pendiente_a_recibida_error() {
+                assert_eq!(
+                    Estado::Pendiente.transicionar(Estado::Recibida),
+                    Err(ErrorSistema::OrdenNoEnviada)
+                );
+            }
+
+            #[test]
+            fn tests_transicionar_enviada_a_enviada_error() {
+                assert_eq!(
+                    Estado::Enviada.transicionar(Estado::Enviada),
+                    Err(ErrorSistema::OrdenNoPendiente)
+                );
+            }
+
+            #[test]
+            fn tests_transicionar_desde_recibida_error() {
+                assert_eq!(
+                    Estado::Recibida.transicionar(Estado::Enviada),
+                    Err(ErrorSistema::OrdenYaRecibida)
+                );
+                assert_eq!(
+                    Estado::Recibida.transicionar(Estado::Recibida),
+                    Err(ErrorSistema::OrdenYaRecibida)
+                );
+            }
+
+            #[test]
+            fn tests_transicionar_desde_cancelada_error() {
+                assert_eq!(
+                    Estado::Cancelada.transicionar(Estado::Enviada),
+                    Err(ErrorSistema::OrdenCancelada)
+                );
+                assert_eq!(
+                    Estado::Cancelada.transicionar(Estado::Recibida),
+                    Err(ErrorSistema::OrdenCancelada)
+                );
+            }
+        }
+
+        mod tests_get_orden_detalle {
+            use super::*;
+
+            #[ink::test]
+            fn tests_orden_guarda_snapshot_de_lo_acordado() {
+                let (marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let orden = marketplace.ordenes_compra.get(0).unwrap();
+
+                assert_eq!(orden.id_publicacion, 0);
+                assert_eq!(orden.vendedor_id, vendedor);
+                assert_eq!(orden.comprador_id, comprador);
+                assert_eq!(orden.nombre_producto, "Remera");
+                assert_eq!(orden.precio_unitario, 12000);
+                assert_eq!(orden.cantidad, 1);
+            }
+
+            #[ink::test]
+            fn tests_orden_no_guarda_la_descripcion() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._publicar(
+                    vendedor,
+                    "Remera".to_string(),
+                    "algodon ".repeat(100),
+                    12000,
+                    ROPA,
+                    20,
+                );
+                let _ = marketplace._ordenar_compra(comprador, 1_u32, 1, 12000);
+
+                let orden_corta = marketplace.ordenes_compra.get(0).unwrap();
+                let orden_larga = marketplace.ordenes_compra.get(1).unwrap();
+                assert_eq!(
+                    ink::scale::Encode::encoded_size(&orden_corta),
+                    ink::scale::Encode::encoded_size(&orden_larga)
+                );
+            }
+
+            #[ink::test]
+            fn tests_get_orden_detalle_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ =
+                    marketplace._editar_publicacion(vendedor, 0_u32, None, None, Some(15000), None);
+
+                let detalle = marketplace._get_orden_detalle(comprador, 0_u32).unwrap();
+
+                assert_eq!(detalle.orden, marketplace.ordenes_compra.get(0).unwrap());
+                assert_eq!(detalle.orden.precio_unitario, 12000);
+                assert_eq!(detalle.publicacion.precio, 15000);
+                assert_eq!(detalle.publicacion.descripcion, "algodon");
+            }
+
+            #[ink::test]
+            fn tests_get_orden_detalle_orden_no_existente() {
+                let (marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result = marketplace._get_orden_detalle(comprador, 1_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenNoExistente));
+            }
+
+            #[ink::test]
+            fn tests_get_orden_detalle_usuario_no_encontrado() {
+                let (marketplace, _, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._get_orden_detalle(AccountId::from([0xCC; 32]), 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+        }
+
+        mod tests_marcar_enviada {
+            use super::*;
+
+            #[ink::test]
+            fn tests_marcar_enviada_correcto() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._marcar_enviada(vendedor, 0_u32);
+
+                assert!(result.is_ok());
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Enviada
+                );
+            }
+
+            #[ink::test]
+            fn tests_marcar_enviada_usuario_no_encontrado() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._marcar_enviada(AccountId::from([0xCC; 32]), 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_marcar_enviada_orden_no_existente() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._marcar_enviada(vendedor, 1_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenNoExistente));
+            }
+
+            #[ink::test]
+            fn tests_marcar_enviada_no_es_vendedor() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result = marketplace._marcar_enviada(comprador, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::NoEsVendedorDeLaOrden));
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Pendiente
+                );
+            }
+
+            #[ink::test]
+            fn tests_marcar_enviada_cancelacion_pendiente() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let result = marketplace._marcar_enviada(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::CancelacionPendiente));
+            }
+
+            #[ink::test]
+            fn tests_marcar_enviada_ya_enviada() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let result = marketplace._marcar_enviada(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenNoPendiente));
+            }
+
+            #[ink::test]
+            fn tests_marcar_enviada_ya_recibida() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
+                let result = marketplace._marcar_enviada(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenYaRecibida));
+            }
+
+            #[ink::test]
+            fn tests_marcar_enviada_cancelada() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let mut orden = marketplace.ordenes_compra.get(0).unwrap();
+                orden.estado = Estado::Cancelada;
+                marketplace.ordenes_compra.insert(0, &orden);
+                let result = marketplace._marcar_enviada(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenCancelada));
             }
         }
 
-        mod tests_es_comprador {
+        mod tests_marcar_recibida {
             use super::*;
 
-            #[test]
-            fn tests_es_comprador_true_comprador() {
-                let usuario = Usuario {
-                    account_id: AccountId::from([0xAA; 32]),
-                    username: "agustin22".to_string(),
-                    rol: Rol::Comprador,
-                };
+            #[ink::test]
+            fn tests_marcar_recibida_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let result = marketplace._marcar_recibida(comprador, 0_u32);
+
+                assert!(result.is_ok());
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Recibida
+                );
+            }
+
+            #[ink::test]
+            fn tests_marcar_recibida_libera_fondos_al_vendedor() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let balance_contrato = balance_de(contrato());
+                let balance_vendedor = balance_de(vendedor);
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
 
-                assert_eq!(usuario.es_comprador().is_ok(), true);
+                assert_eq!(balance_de(vendedor), balance_vendedor + 12000);
+                assert_eq!(balance_de(contrato()), balance_contrato - 12000);
+                assert_eq!(marketplace.fondos_retenidos.get(0_u32), None);
             }
 
-            #[test]
-            fn tests_es_comprador_true_ambos() {
-                let usuario = Usuario {
-                    account_id: AccountId::from([0xAA; 32]),
-                    username: "agustin22".to_string(),
-                    rol: Rol::Ambos,
-                };
+            #[ink::test]
+            fn tests_marcar_recibida_usuario_no_encontrado() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._marcar_recibida(AccountId::from([0xCC; 32]), 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_marcar_recibida_orden_no_existente() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
 
-                assert_eq!(usuario.es_comprador().is_ok(), true);
+                let result = marketplace._marcar_recibida(comprador, 1_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenNoExistente));
             }
 
-            #[test]
-            fn tests_es_comprador_false() {
-                let usuario = Usuario {
-                    account_id: AccountId::from([0xAA; 32]),
-                    username: "agustin22".to_string(),
-                    rol: Rol::Vendedor,
+            #[ink::test]
+            fn tests_marcar_recibida_no_es_comprador() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let result = marketplace._marcar_recibida(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::NoEsCompradorDeLaOrden));
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Enviada
+                );
+            }
+
+            #[ink::test]
+            fn tests_marcar_recibida_sin_enviar() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result = marketplace._marcar_recibida(comprador, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenNoEnviada));
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Pendiente
+                );
+            }
+
+            #[ink::test]
+            fn tests_marcar_recibida_ya_recibida() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
+                let result = marketplace._marcar_recibida(comprador, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenYaRecibida));
+            }
+
+            #[ink::test]
+            fn tests_marcar_recibida_cancelada() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let mut orden = marketplace.ordenes_compra.get(0).unwrap();
+                orden.estado = Estado::Cancelada;
+                marketplace.ordenes_compra.insert(0, &orden);
+                let result = marketplace._marcar_recibida(comprador, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenCancelada));
+            }
+        }
+
+        mod tests_solicitar_cancelacion {
+            use super::*;
+
+            #[ink::test]
+            fn tests_solicitar_cancelacion_correcto() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result = marketplace._solicitar_cancelacion(comprador, 0_u32);
+
+                assert!(result.is_ok());
+                assert!(
+                    marketplace
+                        .ordenes_compra
+                        .get(0)
+                        .unwrap()
+                        .peticion_cancelacion
+                );
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Pendiente
+                );
+            }
+
+            #[ink::test]
+            fn tests_solicitar_cancelacion_usuario_no_encontrado() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._solicitar_cancelacion(AccountId::from([0xCC; 32]), 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_solicitar_cancelacion_orden_no_existente() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result = marketplace._solicitar_cancelacion(comprador, 1_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenNoExistente));
+            }
+
+            #[ink::test]
+            fn tests_solicitar_cancelacion_no_es_comprador() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._solicitar_cancelacion(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::NoEsCompradorDeLaOrden));
+            }
+
+            #[ink::test]
+            fn tests_solicitar_cancelacion_ya_solicitada() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let result = marketplace._solicitar_cancelacion(comprador, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::CancelacionYaSolicitada));
+            }
+
+            #[ink::test]
+            fn tests_solicitar_cancelacion_orden_enviada() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let result = marketplace._solicitar_cancelacion(comprador, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenNoPendiente));
+                assert!(
+                    !marketplace
+                        .ordenes_compra
+                        .get(0)
+                        .unwrap()
+                        .peticion_cancelacion
+                );
+            }
+
+            #[ink::test]
+            fn tests_solicitar_cancelacion_orden_cancelada() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let _ = marketplace._aceptar_cancelacion(vendedor, 0_u32);
+                let result = marketplace._solicitar_cancelacion(comprador, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenCancelada));
+            }
+        }
+
+        mod tests_aceptar_cancelacion {
+            use super::*;
+
+            #[ink::test]
+            fn tests_aceptar_cancelacion_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 19);
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let result = marketplace._aceptar_cancelacion(vendedor, 0_u32);
+
+                assert!(result.is_ok());
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Cancelada
+                );
+                assert!(
+                    !marketplace
+                        .ordenes_compra
+                        .get(0)
+                        .unwrap()
+                        .peticion_cancelacion
+                );
+                assert!(
+                    marketplace
+                        .ordenes_compra
+                        .get(0)
+                        .unwrap()
+                        .cancelacion_solicitada
+                );
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 20);
+            }
+
+            #[ink::test]
+            fn tests_aceptar_cancelacion_devuelve_cantidad_ordenada() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._ordenar_compra(comprador, 0_u32, 3, 36000);
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 16);
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 1_u32);
+                let _ = marketplace._aceptar_cancelacion(vendedor, 1_u32);
+
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 19);
+            }
+
+            #[ink::test]
+            fn tests_aceptar_cancelacion_reintegra_fondos_al_comprador() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let balance_contrato = balance_de(contrato());
+                let balance_comprador = balance_de(comprador);
+                let balance_vendedor = balance_de(vendedor);
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let _ = marketplace._aceptar_cancelacion(vendedor, 0_u32);
+
+                assert_eq!(balance_de(comprador), balance_comprador + 12000);
+                assert_eq!(balance_de(vendedor), balance_vendedor);
+                assert_eq!(balance_de(contrato()), balance_contrato - 12000);
+                assert_eq!(marketplace.fondos_retenidos.get(0_u32), None);
+            }
+
+            #[ink::test]
+            fn tests_aceptar_cancelacion_no_es_vendedor() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let result = marketplace._aceptar_cancelacion(comprador, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::NoEsVendedorDeLaOrden));
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 19);
+            }
+
+            #[ink::test]
+            fn tests_aceptar_cancelacion_no_solicitada() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._aceptar_cancelacion(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::CancelacionNoSolicitada));
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Pendiente
+                );
+            }
+
+            #[ink::test]
+            fn tests_aceptar_cancelacion_orden_no_existente() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._aceptar_cancelacion(vendedor, 1_u32);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenNoExistente));
+            }
+
+            #[ink::test]
+            fn tests_aceptar_cancelacion_dos_veces() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let _ = marketplace._aceptar_cancelacion(vendedor, 0_u32);
+                let result = marketplace._aceptar_cancelacion(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::CancelacionNoSolicitada));
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 20);
+            }
+        }
+
+        mod tests_rechazar_cancelacion {
+            use super::*;
+
+            #[ink::test]
+            fn tests_rechazar_cancelacion_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let result = marketplace._rechazar_cancelacion(vendedor, 0_u32);
+
+                assert!(result.is_ok());
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Pendiente
+                );
+                assert!(
+                    !marketplace
+                        .ordenes_compra
+                        .get(0)
+                        .unwrap()
+                        .peticion_cancelacion
+                );
+                //El registro de que hubo una peticion no se borra al resolverla
+                assert!(
+                    marketplace
+                        .ordenes_compra
+                        .get(0)
+                        .unwrap()
+                        .cancelacion_solicitada
+                );
+                assert_eq!(marketplace.publicaciones.get(0).unwrap().stock, 19);
+
+                assert_eq!(marketplace.fondos_retenidos.get(0_u32), Some(12000));
+
+                //Una vez rechazada el vendedor puede enviar la orden
+                assert!(marketplace._marcar_enviada(vendedor, 0_u32).is_ok());
+            }
+
+            #[ink::test]
+            fn tests_rechazar_cancelacion_no_es_vendedor() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let result = marketplace._rechazar_cancelacion(comprador, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::NoEsVendedorDeLaOrden));
+                assert!(
+                    marketplace
+                        .ordenes_compra
+                        .get(0)
+                        .unwrap()
+                        .peticion_cancelacion
+                );
+            }
+
+            #[ink::test]
+            fn tests_rechazar_cancelacion_no_solicitada() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result = marketplace._rechazar_cancelacion(vendedor, 0_u32);
+
+                assert_eq!(result, Err(ErrorSistema::CancelacionNoSolicitada));
+            }
+        }
+
+        mod tests_suspender_usuario {
+            use super::*;
+
+            #[ink::test]
+            fn tests_suspender_usuario_correcto() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+
+                let result =
+                    marketplace._suspender_usuario(owner(), vendedor, "estafa".to_string(), None);
+
+                let suspension = Suspension {
+                    motivo: "estafa".to_string(),
+                    hasta_bloque: None,
                 };
+                assert_eq!(result, Ok(suspension.clone()));
+                assert_eq!(marketplace.get_suspension(vendedor), Some(suspension));
+            }
+
+            #[ink::test]
+            fn tests_suspender_usuario_bloquea_publicar() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+                let _ = marketplace._suspender_usuario(owner(), vendedor, "spam".to_string(), None);
+
+                let result = marketplace._publicar(
+                    vendedor,
+                    "Taladro".to_string(),
+                    "percutor".to_string(),
+                    50000,
+                    HERRAMIENTAS,
+                    5,
+                );
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioSuspendido));
+                assert_eq!(marketplace.cantidad_publicaciones, 1);
+            }
+
+            #[ink::test]
+            fn tests_suspender_usuario_bloquea_ordenar_compra() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+                let _ =
+                    marketplace._suspender_usuario(owner(), comprador, "abuso".to_string(), None);
+
+                let result = marketplace._ordenar_compra(comprador, 0_u32, 1, 12000);
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioSuspendido));
+                assert_eq!(marketplace.cantidad_ordenes, 1);
+            }
+
+            #[ink::test]
+            fn tests_suspender_usuario_vence_en_bloque() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._suspender_usuario(
+                    owner(),
+                    comprador,
+                    "abuso".to_string(),
+                    Some(2),
+                );
+
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+                assert_eq!(
+                    marketplace._ordenar_compra(comprador, 0_u32, 1, 12000),
+                    Err(ErrorSistema::UsuarioSuspendido)
+                );
+
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+                assert_eq!(marketplace.get_suspension(comprador), None);
+                assert!(marketplace
+                    ._ordenar_compra(comprador, 0_u32, 1, 12000)
+                    .is_ok());
+            }
+
+            #[ink::test]
+            fn tests_suspender_usuario_admin() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+                let admin = AccountId::from([0xAD; 32]);
+                let _ = marketplace._agregar_admin(owner(), admin);
+
+                assert!(marketplace
+                    ._suspender_usuario(admin, vendedor, "spam".to_string(), None)
+                    .is_ok());
+            }
+
+            #[ink::test]
+            fn tests_suspender_usuario_no_autorizado() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let result =
+                    marketplace._suspender_usuario(vendedor, comprador, "spam".to_string(), None);
+
+                assert_eq!(result, Err(ErrorSistema::NoAutorizado));
+                assert_eq!(marketplace.get_suspension(comprador), None);
+            }
+
+            #[ink::test]
+            fn tests_suspender_usuario_no_registrado() {
+                let mut marketplace = Marketplace::new();
+
+                let result = marketplace._suspender_usuario(
+                    owner(),
+                    AccountId::from([0xCC; 32]),
+                    "spam".to_string(),
+                    None,
+                );
+
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            }
+
+            #[ink::test]
+            fn tests_levantar_suspension_correcto() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+                let _ =
+                    marketplace._suspender_usuario(owner(), comprador, "abuso".to_string(), None);
+
+                assert_eq!(marketplace._levantar_suspension(owner(), comprador), Ok(()));
+
+                assert_eq!(marketplace.get_suspension(comprador), None);
+                assert!(marketplace
+                    ._ordenar_compra(comprador, 0_u32, 1, 12000)
+                    .is_ok());
+            }
+
+            #[ink::test]
+            fn tests_levantar_suspension_no_autorizado() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ =
+                    marketplace._suspender_usuario(owner(), comprador, "abuso".to_string(), None);
+
+                assert_eq!(
+                    marketplace._levantar_suspension(vendedor, comprador),
+                    Err(ErrorSistema::NoAutorizado)
+                );
+                assert!(marketplace.get_suspension(comprador).is_some());
+            }
+        }
+
+        mod tests_dar_de_baja_publicacion {
+            use super::*;
 
-                assert_eq!(usuario.es_comprador().is_ok(), false);
+            #[ink::test]
+            fn tests_dar_de_baja_publicacion_correcto() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let result =
+                    marketplace._dar_de_baja_publicacion(owner(), 0_u32, "estafa".to_string());
+
+                let publicacion = result.unwrap();
+                assert!(publicacion.dada_de_baja);
+                assert!(!publicacion.activa);
+                assert_eq!(marketplace._get_publicaciones(comprador), Ok(Vec::new()));
             }
-        }
 
-        mod tests_registrar_usuario {
-            use super::*;
+            #[ink::test]
+            fn tests_dar_de_baja_publicacion_rechaza_ordenes() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._dar_de_baja_publicacion(owner(), 0_u32, "estafa".to_string());
+
+                let result = marketplace._ordenar_compra(comprador, 0_u32, 1, 12000);
+
+                assert_eq!(result, Err(ErrorSistema::PublicacionDadaDeBaja));
+            }
 
             #[ink::test]
-            fn tests_registrar_usuario_no_registrado() {
-                let mut marketplace = Marketplace::new();
+            fn tests_dar_de_baja_publicacion_cancela_ordenes_pendientes() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._ordenar_compra(comprador, 0_u32, 2, 24000);
+                let _ = marketplace._marcar_enviada(vendedor, 1_u32);
+                let balance_comprador = balance_de(comprador);
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
+                let publicacion = marketplace
+                    ._dar_de_baja_publicacion(owner(), 0_u32, "estafa".to_string())
+                    .unwrap();
 
+                //Solo se cancela la orden pendiente, la enviada sigue su curso
                 assert_eq!(
-                    marketplace
-                        ._registrar_usuario(caller, username, rol)
-                        .is_ok(),
-                    true
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Cancelada
                 );
+                assert_eq!(
+                    marketplace.ordenes_compra.get(1).unwrap().estado,
+                    Estado::Enviada
+                );
+                assert_eq!(publicacion.stock, 18);
+                assert_eq!(publicacion.unidades_vendidas, 2);
+                assert_eq!(balance_de(comprador), balance_comprador + 12000);
+                assert_eq!(marketplace.fondos_retenidos.get(0_u32), None);
+                assert_eq!(marketplace.fondos_retenidos.get(1_u32), Some(24000));
             }
 
             #[ink::test]
-            fn tests_registrar_usuario_ya_registrado_error() {
-                let mut marketplace = Marketplace::new();
+            fn tests_dar_de_baja_publicacion_no_cancela_otras_publicaciones() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._publicar(
+                    vendedor,
+                    "Pantalon".to_string(),
+                    "jean".to_string(),
+                    20000,
+                    ROPA,
+                    5,
+                );
+                let _ = marketplace._ordenar_compra(comprador, 1_u32, 1, 20000);
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
+                let _ = marketplace._dar_de_baja_publicacion(owner(), 0_u32, "estafa".to_string());
 
                 assert_eq!(
-                    marketplace
-                        ._registrar_usuario(caller.clone(), username.clone(), rol.clone())
-                        .is_ok(),
-                    true
+                    marketplace.ordenes_compra.get(1).unwrap().estado,
+                    Estado::Pendiente
                 );
+                assert_eq!(marketplace.ordenes_publicacion.get((1, 0)), Some(1));
+                assert_eq!(marketplace.cantidad_ordenes_publicacion.get(0), Some(1));
+            }
 
-                let result = marketplace._registrar_usuario(caller, username, rol);
+            #[ink::test]
+            fn tests_dar_de_baja_publicacion_no_se_puede_reanudar() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
+                let _ = marketplace._dar_de_baja_publicacion(owner(), 0_u32, "estafa".to_string());
 
-                assert_eq!(result, Err(ErrorSistema::UsuarioYaRegistrado));
-            }
-        }
+                let result = marketplace._reanudar_publicacion(vendedor, 0_u32);
 
-        mod tests_get_usuario {
-            use super::*;
+                assert_eq!(result, Err(ErrorSistema::PublicacionDadaDeBaja));
+                assert!(!marketplace.publicaciones.get(0).unwrap().activa);
+            }
 
             #[ink::test]
-            fn tests_get_usuario_encontrado() {
-                let mut marketplace = Marketplace::new();
-
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
+            fn tests_dar_de_baja_publicacion_dos_veces() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
+                let _ = marketplace._dar_de_baja_publicacion(owner(), 0_u32, "estafa".to_string());
 
-                let _ = marketplace._registrar_usuario(caller, username, rol);
+                let result =
+                    marketplace._dar_de_baja_publicacion(owner(), 0_u32, "estafa".to_string());
 
-                assert_eq!(marketplace._get_usuario(caller).is_ok(), true);
+                assert_eq!(result, Err(ErrorSistema::PublicacionDadaDeBaja));
             }
 
             #[ink::test]
-            fn tests_get_usuario_no_encontrado() {
-                let mut marketplace = Marketplace::new();
+            fn tests_dar_de_baja_publicacion_no_autorizado() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
 
-                let caller = AccountId::from([0xAA; 32]);
+                let result =
+                    marketplace._dar_de_baja_publicacion(vendedor, 0_u32, "estafa".to_string());
 
-                let result = marketplace._get_usuario(caller);
+                assert_eq!(result, Err(ErrorSistema::NoAutorizado));
+                assert!(!marketplace.publicaciones.get(0).unwrap().dada_de_baja);
+                assert_eq!(
+                    marketplace.ordenes_compra.get(0).unwrap().estado,
+                    Estado::Pendiente
+                );
+            }
 
-                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            #[ink::test]
+            fn tests_dar_de_baja_publicacion_no_existente() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
+
+                let result =
+                    marketplace._dar_de_baja_publicacion(owner(), 1_u32, "estafa".to_string());
+
+                assert_eq!(result, Err(ErrorSistema::PublicacionNoExistente));
             }
         }
 
-        mod tests_publicar {
+        mod tests_calificar_vendedor {
             use super::*;
 
+            fn crear_marketplace_con_orden_recibida() -> (Marketplace, AccountId, AccountId) {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
+                (marketplace, vendedor, comprador)
+            }
+
             #[ink::test]
-            fn tests_publicar_correcto() {
-                let mut marketplace = Marketplace::new();
+            fn tests_calificar_vendedor_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden_recibida();
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
+                let result = marketplace._calificar_vendedor(comprador, 0_u32, 4);
+
+                let esperada = Reputacion {
+                    puntaje_total: 4,
+                    cantidad_calificaciones: 1,
+                };
+                assert_eq!(result, Ok(esperada.clone()));
+                assert_eq!(marketplace.get_reputacion(vendedor), Ok(esperada));
+                assert!(
+                    marketplace
+                        .ordenes_compra
+                        .get(0)
+                        .unwrap()
+                        .calificada_por_comprador
+                );
+            }
 
-                let _ = marketplace._registrar_usuario(caller.clone(), username, rol);
+            #[ink::test]
+            fn tests_calificar_vendedor_acumula_varias_ordenes() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden_recibida();
 
-                let nombre_producto = "Remera".to_string();
-                let descripcion = "algodon".to_string();
-                let precio = 12000;
-                let categoria = Categoria::Ropa;
-                let stock = 20;
+                let _ = marketplace._ordenar_compra(comprador, 0_u32, 1, 12000);
+                let _ = marketplace._marcar_enviada(vendedor, 1_u32);
+                let _ = marketplace._marcar_recibida(comprador, 1_u32);
+
+                let _ = marketplace._calificar_vendedor(comprador, 0_u32, 5);
+                let _ = marketplace._calificar_vendedor(comprador, 1_u32, 2);
 
                 assert_eq!(
-                    marketplace
-                        ._publicar(
-                            caller,
-                            nombre_producto,
-                            descripcion,
-                            precio,
-                            categoria,
-                            stock
-                        )
-                        .is_ok(),
-                    true
+                    marketplace.get_reputacion(vendedor),
+                    Ok(Reputacion {
+                        puntaje_total: 7,
+                        cantidad_calificaciones: 2,
+                    })
                 );
             }
 
             #[ink::test]
-            fn tests_publicar_usuario_no_encontrado() {
-                let mut marketplace = Marketplace::new();
+            fn tests_calificar_vendedor_ya_calificada() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden_recibida();
 
-                let caller = AccountId::from([0xAA; 32]);
+                let _ = marketplace._calificar_vendedor(comprador, 0_u32, 4);
+                let result = marketplace._calificar_vendedor(comprador, 0_u32, 1);
 
-                let nombre_producto = "Remera".to_string();
-                let descripcion = "algodon".to_string();
-                let precio = 12000;
-                let categoria = Categoria::Ropa;
-                let stock = 20;
+                assert_eq!(result, Err(ErrorSistema::OrdenYaCalificada));
+                assert_eq!(
+                    marketplace.get_reputacion(vendedor).unwrap().puntaje_total,
+                    4
+                );
+            }
 
-                let result = marketplace._publicar(
-                    caller,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
+            #[ink::test]
+            fn tests_calificar_vendedor_puntaje_invalido() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden_recibida();
+
+                assert_eq!(
+                    marketplace._calificar_vendedor(comprador, 0_u32, 0),
+                    Err(ErrorSistema::PuntajeInvalido)
+                );
+                assert_eq!(
+                    marketplace._calificar_vendedor(comprador, 0_u32, 6),
+                    Err(ErrorSistema::PuntajeInvalido)
                 );
+            }
 
-                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            #[ink::test]
+            fn tests_calificar_vendedor_orden_no_recibida() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                assert_eq!(
+                    marketplace._calificar_vendedor(comprador, 0_u32, 5),
+                    Err(ErrorSistema::OrdenNoRecibida)
+                );
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                assert_eq!(
+                    marketplace._calificar_vendedor(comprador, 0_u32, 5),
+                    Err(ErrorSistema::OrdenNoRecibida)
+                );
             }
 
             #[ink::test]
-            fn tests_publicar_usuario_no_vendedor() {
-                let mut marketplace = Marketplace::new();
+            fn tests_calificar_vendedor_no_es_comprador() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden_recibida();
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Comprador;
+                let result = marketplace._calificar_vendedor(vendedor, 0_u32, 5);
 
-                let _ = marketplace._registrar_usuario(caller.clone(), username, rol);
+                assert_eq!(result, Err(ErrorSistema::NoEsCompradorDeLaOrden));
+            }
 
-                let nombre_producto = "Remera".to_string();
-                let descripcion = "algodon".to_string();
-                let precio = 12000;
-                let categoria = Categoria::Ropa;
-                let stock = 20;
+            #[ink::test]
+            fn tests_calificar_vendedor_orden_no_existente() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden_recibida();
 
-                let result = marketplace._publicar(
-                    caller,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
-                );
+                let result = marketplace._calificar_vendedor(comprador, 7_u32, 5);
 
-                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsVendedor));
+                assert_eq!(result, Err(ErrorSistema::OrdenNoExistente));
             }
         }
 
-        mod tests_get_publicaciones_vendedor {
+        mod tests_calificar_comprador {
             use super::*;
 
             #[ink::test]
-            fn tests_get_publicaciones_vendedor_correcto() {
-                let mut marketplace = Marketplace::new();
+            fn tests_calificar_comprador_correcto() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
-
-                let _ = marketplace._registrar_usuario(caller.clone(), username, rol);
-
-                let mut nombre_producto = "Remera".to_string();
-                let mut descripcion = "algodon".to_string();
-                let mut precio = 12000;
-                let mut categoria = Categoria::Ropa;
-                let mut stock = 20;
+                let result = marketplace._calificar_comprador(vendedor, 0_u32, 3);
 
-                let _ = marketplace._publicar(
-                    caller,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
+                assert_eq!(
+                    result,
+                    Ok(Reputacion {
+                        puntaje_total: 3,
+                        cantidad_calificaciones: 1,
+                    })
                 );
-
-                nombre_producto = "Pantalon".to_string();
-                descripcion = "Jean".to_string();
-                precio = 20000;
-                categoria = Categoria::Ropa;
-                stock = 5;
-
-                let _ = marketplace._publicar(
-                    caller,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
+                //La calificacion del vendedor no afecta la del comprador y viceversa
+                assert_eq!(
+                    marketplace.get_reputacion(vendedor),
+                    Ok(Reputacion::default())
                 );
-
+                assert!(marketplace._calificar_vendedor(comprador, 0_u32, 5).is_ok());
                 assert_eq!(
-                    marketplace._get_publicaciones_vendedor(caller).is_ok(),
-                    true
+                    marketplace._calificar_comprador(vendedor, 0_u32, 3),
+                    Err(ErrorSistema::OrdenYaCalificada)
                 );
+            }
 
-                if let Ok(vec_publicaciones) = marketplace._get_publicaciones_vendedor(caller) {
-                    assert_eq!(vec_publicaciones.len(), 2);
-                }
+            #[ink::test]
+            fn tests_calificar_comprador_no_es_vendedor() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
+
+                let result = marketplace._calificar_comprador(comprador, 0_u32, 3);
+
+                assert_eq!(result, Err(ErrorSistema::NoEsVendedorDeLaOrden));
             }
 
             #[ink::test]
-            fn tests_get_publicaciones_vendedor_usuario_no_encontrado() {
-                let mut marketplace = Marketplace::new();
+            fn tests_calificar_comprador_orden_cancelada() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let _ = marketplace._aceptar_cancelacion(vendedor, 0_u32);
 
-                let caller = AccountId::from([0xAA; 32]);
+                let result = marketplace._calificar_comprador(vendedor, 0_u32, 1);
+
+                assert_eq!(result, Err(ErrorSistema::OrdenNoRecibida));
+            }
+
+            #[ink::test]
+            fn tests_calificar_comprador_usuario_no_registrado() {
+                let (mut marketplace, _, _) = crear_marketplace_con_orden();
 
-                let result = marketplace._get_publicaciones_vendedor(caller);
+                let result = marketplace._calificar_comprador(AccountId::from([0xEE; 32]), 0, 3);
 
                 assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
             }
+        }
 
-            #[ink::test]
-            fn tests_get_publicaciones_vendedor_usuario_no_vendedor() {
-                let mut marketplace = Marketplace::new();
+        mod tests_get_reputacion {
+            use super::*;
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Comprador;
+            #[ink::test]
+            fn tests_get_reputacion_sin_calificaciones() {
+                let (marketplace, vendedor, _) = crear_marketplace_con_orden();
 
-                let _ = marketplace._registrar_usuario(caller.clone(), username, rol);
+                assert_eq!(
+                    marketplace.get_reputacion(vendedor),
+                    Ok(Reputacion::default())
+                );
+            }
 
-                let result = marketplace._get_publicaciones_vendedor(caller);
+            #[ink::test]
+            fn tests_get_reputacion_usuario_no_registrado() {
+                let marketplace = Marketplace::new();
 
-                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsVendedor));
+                assert_eq!(
+                    marketplace.get_reputacion(AccountId::from([0xEE; 32])),
+                    Err(ErrorSistema::UsuarioNoRegistrado)
+                );
             }
         }
 
-        mod tests_get_publicaciones {
+        mod tests_rango_pagina {
             use super::*;
 
-            #[ink::test]
-            fn tests_get_publicaciones_correcto() {
-                let mut marketplace = Marketplace::new();
+            #[test]
+            fn tests_rango_pagina_primera_pagina() {
+                assert_eq!(rango_pagina(10, 0, 3), (0, 3));
+            }
 
-                let caller1 = AccountId::from([0xAA; 32]);
-                let username1 = "agustin".to_string();
-                let rol1 = Rol::Ambos;
+            #[test]
+            fn tests_rango_pagina_ultima_pagina_incompleta() {
+                assert_eq!(rango_pagina(10, 9, 3), (9, 10));
+            }
 
-                let caller2 = AccountId::from([0xAA; 32]);
-                let username2 = "agustin".to_string();
-                let rol2 = Rol::Ambos;
+            #[test]
+            fn tests_rango_pagina_offset_fuera_de_rango() {
+                assert_eq!(rango_pagina(10, 20, 3), (10, 10));
+                assert_eq!(rango_pagina(10, u32::MAX, u32::MAX), (10, 10));
+            }
 
-                let _ = marketplace._registrar_usuario(caller1.clone(), username1, rol1);
-                let _ = marketplace._registrar_usuario(caller2.clone(), username2, rol2);
+            #[test]
+            fn tests_rango_pagina_limite_acotado() {
+                assert_eq!(rango_pagina(1000, 0, 500), (0, MAX_LIMITE_PAGINA));
+            }
 
-                let mut nombre_producto = "Remera".to_string();
-                let mut descripcion = "algodon".to_string();
-                let mut precio = 12000;
-                let mut categoria = Categoria::Ropa;
-                let mut stock = 20;
+            #[test]
+            fn tests_rango_pagina_limite_cero() {
+                assert_eq!(rango_pagina(10, 2, 0), (2, 2));
+            }
+        }
 
-                let _ = marketplace._publicar(
-                    caller1,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
-                );
+        mod tests_paginado {
+            use super::*;
 
-                nombre_producto = "Pantalon".to_string();
-                descripcion = "Jean".to_string();
-                precio = 20000;
-                categoria = Categoria::Ropa;
-                stock = 5;
+            //Crea un vendedor con `cantidad` publicaciones y un comprador con una orden
+            //por cada publicacion
+            fn crear_marketplace_con_publicaciones(
+                cantidad: u32,
+            ) -> (Marketplace, AccountId, AccountId) {
+                let mut marketplace = Marketplace::new();
 
-                let _ = marketplace._publicar(
-                    caller1,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
-                );
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+
+                let _ =
+                    marketplace._registrar_usuario(vendedor, "agustin".to_string(), Rol::Vendedor);
+                let _ =
+                    marketplace._registrar_usuario(comprador, "juan".to_string(), Rol::Comprador);
+
+                for i in 0..cantidad {
+                    let _ = marketplace._publicar(
+                        vendedor,
+                        "Remera".to_string(),
+                        "algodon".to_string(),
+                        1000,
+                        ROPA,
+                        5,
+                    );
+                    let _ = marketplace._ordenar_compra(comprador, i, 1, 1000);
+                }
 
-                nombre_producto = "Notebook".to_string();
-                descripcion = "Ryzen 7".to_string();
-                precio = 200000;
-                categoria = Categoria::Computacion;
-                stock = 10;
+                (marketplace, vendedor, comprador)
+            }
 
-                let _ = marketplace._publicar(
-                    caller2,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
-                );
+            #[ink::test]
+            fn tests_get_publicaciones_paginado_correcto() {
+                let (marketplace, vendedor, _) = crear_marketplace_con_publicaciones(5);
+
+                let pagina = marketplace
+                    ._get_publicaciones_paginado(vendedor, 2, 2)
+                    .unwrap();
+
+                assert_eq!(pagina.total, 5);
+                assert_eq!(pagina.elementos.len(), 2);
+                assert_eq!(pagina.elementos[0].id_publicacion, 2);
+                assert_eq!(pagina.elementos[1].id_publicacion, 3);
+            }
+
+            #[ink::test]
+            fn tests_get_publicaciones_paginado_omite_pausadas() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_publicaciones(5);
+                let _ = marketplace._pausar_publicacion(vendedor, 1);
+                let _ = marketplace._pausar_publicacion(vendedor, 4);
+
+                let primera = marketplace
+                    ._get_publicaciones_paginado(vendedor, 0, 3)
+                    .unwrap();
+                let segunda = marketplace
+                    ._get_publicaciones_paginado(vendedor, 3, 3)
+                    .unwrap();
+
+                //El total cuenta los ids, la pagina solo trae las activas
+                assert_eq!(primera.total, 5);
+                assert_eq!(primera.elementos.len(), 2);
+                assert_eq!(segunda.total, 5);
+                assert_eq!(segunda.elementos.len(), 1);
+                assert_eq!(segunda.elementos[0].id_publicacion, 3);
+            }
 
-                assert_eq!(marketplace._get_publicaciones(caller1).is_ok(), true);
+            #[ink::test]
+            fn tests_get_publicaciones_paginado_fuera_de_rango() {
+                let (marketplace, vendedor, _) = crear_marketplace_con_publicaciones(5);
 
-                if let Ok(vec_publicaciones) = marketplace._get_publicaciones(caller1) {
-                    assert_eq!(vec_publicaciones.len(), 3);
-                }
+                let pagina = marketplace
+                    ._get_publicaciones_paginado(vendedor, 5, 10)
+                    .unwrap();
+
+                assert_eq!(pagina.total, 5);
+                assert!(pagina.elementos.is_empty());
             }
 
             #[ink::test]
-            fn tests_get_publicaciones_usuario_no_encontrado() {
-                let mut marketplace = Marketplace::new();
+            fn tests_get_publicaciones_paginado_limite_maximo() {
+                let (marketplace, vendedor, _) =
+                    crear_marketplace_con_publicaciones(MAX_LIMITE_PAGINA + 5);
 
-                let caller = AccountId::from([0xAA; 32]);
+                let pagina = marketplace
+                    ._get_publicaciones_paginado(vendedor, 0, u32::MAX)
+                    .unwrap();
 
-                let result = marketplace._get_publicaciones(caller);
+                assert_eq!(pagina.total, MAX_LIMITE_PAGINA + 5);
+                assert_eq!(pagina.elementos.len(), MAX_LIMITE_PAGINA as usize);
+            }
+
+            #[ink::test]
+            fn tests_get_publicaciones_paginado_usuario_no_encontrado() {
+                let (marketplace, _, _) = crear_marketplace_con_publicaciones(1);
+
+                let result =
+                    marketplace._get_publicaciones_paginado(AccountId::from([0xCC; 32]), 0, 10);
 
                 assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
             }
-        }
-
-        mod tests_ordenar_compra {
-            use super::*;
 
             #[ink::test]
-            fn tests_ordenar_compra_correcto() {
-                let mut marketplace = Marketplace::new();
+            fn tests_get_publicaciones_vendedor_paginado_correcto() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_publicaciones(3);
+
+                //Publicacion de otro vendedor que no debe aparecer
+                let otro_vendedor = AccountId::from([0xCC; 32]);
+                let _ = marketplace._registrar_usuario(
+                    otro_vendedor,
+                    "pedro".to_string(),
+                    Rol::Vendedor,
+                );
+                let _ = marketplace._publicar(
+                    otro_vendedor,
+                    "Silla".to_string(),
+                    "madera".to_string(),
+                    5000,
+                    MUEBLES,
+                    2,
+                );
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
+                let pagina = marketplace
+                    ._get_publicaciones_vendedor_paginado(vendedor, 1, 10)
+                    .unwrap();
 
-                let _ = marketplace._registrar_usuario(caller, username, rol);
+                assert_eq!(pagina.total, 3);
+                assert_eq!(pagina.elementos.len(), 2);
+                assert!(pagina.elementos.iter().all(|p| p.vendedor_id == vendedor));
+            }
 
-                let nombre_producto = "Remera".to_string();
-                let descripcion = "algodon".to_string();
-                let precio = 12000;
-                let categoria = Categoria::Ropa;
-                let stock = 20;
+            #[ink::test]
+            fn tests_get_publicaciones_vendedor_paginado_usuario_no_vendedor() {
+                let (marketplace, _, comprador) = crear_marketplace_con_publicaciones(1);
 
-                let _ = marketplace._publicar(
-                    caller,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
-                );
+                let result = marketplace._get_publicaciones_vendedor_paginado(comprador, 0, 10);
 
-                let orden = marketplace._ordenar_compra(caller, 0_u32);
-                assert!(orden.is_ok());
-                assert!(marketplace.publicaciones[0].stock == 19);
+                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsVendedor));
             }
 
             #[ink::test]
-            fn tests_ordenar_compra_usuario_no_encontrado() {
-                let mut marketplace = Marketplace::new();
+            fn tests_get_ordenes_paginado_correcto() {
+                let (marketplace, vendedor, _) = crear_marketplace_con_publicaciones(4);
 
-                let caller = AccountId::from([0xAA; 32]);
+                let pagina = marketplace._get_ordenes_paginado(vendedor, 3, 2).unwrap();
+
+                assert_eq!(pagina.total, 4);
+                assert_eq!(pagina.elementos.len(), 1);
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_paginado_usuario_no_encontrado() {
+                let (marketplace, _, _) = crear_marketplace_con_publicaciones(1);
 
-                let result = marketplace._ordenar_compra(caller, 0_u32);
+                let result = marketplace._get_ordenes_paginado(AccountId::from([0xCC; 32]), 0, 10);
 
                 assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
             }
 
             #[ink::test]
-            fn tests_ordenar_compra_usuario_no_comprador() {
-                let mut marketplace = Marketplace::new();
+            fn tests_get_ordenes_comprador_paginado_correcto() {
+                let (marketplace, _, comprador) = crear_marketplace_con_publicaciones(4);
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Vendedor;
+                let pagina = marketplace
+                    ._get_ordenes_comprador_paginado(comprador, 0, 3)
+                    .unwrap();
 
-                let _ = marketplace._registrar_usuario(caller.clone(), username, rol);
+                assert_eq!(pagina.total, 4);
+                assert_eq!(pagina.elementos.len(), 3);
+                assert!(pagina.elementos.iter().all(|o| o.comprador_id == comprador));
+            }
+
+            #[ink::test]
+            fn tests_get_ordenes_comprador_paginado_usuario_no_comprador() {
+                let (marketplace, vendedor, _) = crear_marketplace_con_publicaciones(1);
 
-                let result = marketplace._ordenar_compra(caller, 0 as u32);
+                let result = marketplace._get_ordenes_comprador_paginado(vendedor, 0, 10);
 
                 assert_eq!(result, Err(ErrorSistema::UsuarioNoEsComprador));
             }
+        }
 
-            #[ink::test]
-            fn tests_ordenar_compra_publicacion_no_existente() {
-                let mut marketplace = Marketplace::new();
+        mod tests_storage {
+            use super::*;
+            use ink::storage::traits::Storable;
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
+            #[ink::test]
+            fn tests_storage_raiz_no_crece_con_las_entradas() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+                let tamanio_inicial = Storable::encoded_size(&marketplace);
+
+                for _ in 0..10 {
+                    let _ = marketplace._publicar(
+                        vendedor,
+                        "Notebook".to_string(),
+                        "Ryzen 7".to_string(),
+                        200000,
+                        COMPUTACION,
+                        10,
+                    );
+                    let _ = marketplace._ordenar_compra(comprador, 0_u32, 1, 12000);
+                }
 
-                let _ = marketplace._registrar_usuario(caller.clone(), username, rol);
+                assert_eq!(marketplace.cantidad_publicaciones, 11);
+                assert_eq!(marketplace.cantidad_ordenes, 11);
+                assert_eq!(Storable::encoded_size(&marketplace), tamanio_inicial);
+            }
 
-                let mut nombre_producto = "Remera".to_string();
-                let mut descripcion = "algodon".to_string();
-                let mut precio = 12000;
-                let mut categoria = Categoria::Ropa;
-                let mut stock = 20;
+            #[ink::test]
+            fn tests_storage_mappings_consistentes() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
 
                 let _ = marketplace._publicar(
-                    caller,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
+                    vendedor,
+                    "Pantalon".to_string(),
+                    "Jean".to_string(),
+                    20000,
+                    ROPA,
+                    5,
                 );
+                let _ = marketplace._ordenar_compra(comprador, 1_u32, 2, 40000);
+
+                let ids_publicaciones = marketplace.publicaciones_mapping.get(vendedor).unwrap();
+                assert_eq!(ids_publicaciones, vec![0, 1]);
+                for id in ids_publicaciones {
+                    let publicacion = marketplace.publicaciones.get(id).unwrap();
+                    assert_eq!(publicacion.id_publicacion, u64::from(id));
+                    assert_eq!(publicacion.vendedor_id, vendedor);
+                }
 
-                let result = marketplace._ordenar_compra(caller, 1 as u32);
+                let ids_ordenes = marketplace.ordenes_compra_mapping.get(comprador).unwrap();
+                assert_eq!(ids_ordenes, vec![0, 1]);
+                for id in ids_ordenes {
+                    let orden = marketplace.ordenes_compra.get(id).unwrap();
+                    assert_eq!(orden.comprador_id, comprador);
+                }
 
-                assert_eq!(result, Err(ErrorSistema::PublicacionNoExistente));
+                assert_eq!(marketplace.publicaciones.get(2), None);
+                assert_eq!(marketplace.ordenes_compra.get(2), None);
             }
 
             #[ink::test]
-            fn tests_ordenar_compra_publicacion_sin_stock() {
+            fn tests_storage_indice_vendedor_una_entrada_por_orden() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                for _ in 0..10 {
+                    let _ = marketplace._ordenar_compra(comprador, 0_u32, 1, 12000);
+                }
+
+                //Cada compra agrega una entrada propia en vez de reescribir una lista
+                assert_eq!(
+                    marketplace.cantidad_ordenes_vendedor.get(vendedor),
+                    Some(11)
+                );
+                for posicion in 0..11 {
+                    assert_eq!(
+                        marketplace
+                            .ordenes_vendedor_mapping
+                            .get((vendedor, posicion)),
+                        Some(posicion)
+                    );
+                }
+                assert_eq!(
+                    marketplace.ordenes_vendedor_mapping.get((vendedor, 11)),
+                    None
+                );
+            }
+        }
+
+        mod tests_eventos {
+            use super::*;
+            use ink::env::test::EmittedEvent;
+
+            //Omite los eventos CategoriaCreada que emite el constructor
+            fn eventos() -> Vec<EmittedEvent> {
+                ink::env::test::recorded_events()
+                    .skip(CATEGORIAS_INICIALES.len())
+                    .collect()
+            }
+
+            fn decodificar<T: ink::scale::Decode>(evento: &EmittedEvent) -> T {
+                T::decode(&mut &evento.data[..]).expect("evento mal codificado")
+            }
+
+            //Los AccountId se publican como topic con sus 32 bytes sin hashear
+            fn topic(account_id: AccountId) -> Vec<u8> {
+                AsRef::<[u8]>::as_ref(&account_id).to_vec()
+            }
+
+            //Los ids se publican codificados y completados con ceros hasta 32 bytes
+            fn topic_id(id: u32) -> Vec<u8> {
+                let mut topic = id.to_le_bytes().to_vec();
+                topic.resize(32, 0);
+                topic
+            }
+
+            #[ink::test]
+            fn tests_evento_usuario_registrado() {
                 let mut marketplace = Marketplace::new();
 
                 let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
+                let _ = marketplace._registrar_usuario(caller, "agustin".to_string(), Rol::Ambos);
 
-                let _ = marketplace._registrar_usuario(caller, username, rol);
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 1);
+                assert_eq!(eventos[0].topics[1], topic(caller));
 
-                let nombre_producto = "Remera".to_string();
-                let descripcion = "algodon".to_string();
-                let precio = 12000;
-                let categoria = Categoria::Ropa;
-                let stock = 0;
+                let evento: UsuarioRegistrado = decodificar(&eventos[0]);
+                assert_eq!(evento.account_id, caller);
+                assert_eq!(evento.username, "agustin");
+                assert_eq!(evento.rol, Rol::Ambos);
+            }
 
-                let _ = marketplace._publicar(
-                    caller,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
-                );
+            #[ink::test]
+            fn tests_evento_usuario_ya_registrado_no_emite() {
+                let mut marketplace = Marketplace::new();
 
-                let result = marketplace._ordenar_compra(caller, 0_u32);
+                let caller = AccountId::from([0xAA; 32]);
+                let _ = marketplace._registrar_usuario(caller, "agustin".to_string(), Rol::Ambos);
+                let _ = marketplace._registrar_usuario(caller, "agustin".to_string(), Rol::Ambos);
 
-                assert_eq!(result, Err(ErrorSistema::PublicacionSinStock));
+                assert_eq!(eventos().len(), 1);
             }
-        }
-
-        mod tests_get_ordenes_comprador {
-            use super::*;
 
             #[ink::test]
-            fn tests_get_ordenes_comprador_correcto() {
+            fn tests_evento_usuario_actualizado() {
                 let mut marketplace = Marketplace::new();
 
                 let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
+                let _ =
+                    marketplace._registrar_usuario(caller, "agustin".to_string(), Rol::Comprador);
+                let _ = marketplace._cambiar_rol(caller, Rol::Ambos);
+                let _ = marketplace._editar_username(caller, "agus".to_string());
+
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 3);
+                assert_eq!(eventos[1].topics[1], topic(caller));
+
+                let rol: UsuarioActualizado = decodificar(&eventos[1]);
+                assert_eq!(rol.username, "agustin");
+                assert_eq!(rol.rol, Rol::Ambos);
+
+                let username: UsuarioActualizado = decodificar(&eventos[2]);
+                assert_eq!(username.username, "agus");
+                assert_eq!(username.rol, Rol::Ambos);
+            }
 
-                let _ = marketplace._registrar_usuario(caller, username, rol);
+            #[ink::test]
+            fn tests_evento_admins_y_ownership() {
+                let mut marketplace = Marketplace::new();
+                let admin = AccountId::from([0xAD; 32]);
+
+                let _ = marketplace._agregar_admin(owner(), admin);
+                let _ = marketplace._agregar_admin(owner(), admin);
+                let _ = marketplace._quitar_admin(owner(), admin);
+                let _ = marketplace._transferir_ownership(owner(), admin);
+                let _ = marketplace._aceptar_ownership(admin);
+
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 4);
+                assert_eq!(eventos[0].topics[1], topic(admin));
+                assert_eq!(eventos[1].topics[1], topic(admin));
+
+                let propuesta: OwnershipPropuesta = decodificar(&eventos[2]);
+                assert_eq!(eventos[2].topics[1], topic(owner()));
+                assert_eq!(eventos[2].topics[2], topic(admin));
+                assert_eq!(propuesta.owner, owner());
+                assert_eq!(propuesta.nuevo_owner, admin);
+
+                let transferido: OwnershipTransferido = decodificar(&eventos[3]);
+                assert_eq!(eventos[3].topics[1], topic(owner()));
+                assert_eq!(eventos[3].topics[2], topic(admin));
+                assert_eq!(transferido.owner_anterior, owner());
+                assert_eq!(transferido.owner_nuevo, admin);
+            }
 
-                let mut nombre_producto = "Remera".to_string();
-                let mut descripcion = "algodon".to_string();
-                let mut precio = 12000;
-                let mut categoria = Categoria::Ropa;
-                let mut stock = 20;
+            #[ink::test]
+            fn tests_evento_ownership_propuesta_solo_owner() {
+                let mut marketplace = Marketplace::new();
+                let otro = AccountId::from([0xAD; 32]);
 
-                let _ = marketplace._publicar(
-                    caller,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
-                );
+                let _ = marketplace._transferir_ownership(otro, otro);
 
-                let _ = marketplace._ordenar_compra(caller, 0_u32);
+                assert!(eventos().is_empty());
+            }
 
-                nombre_producto = "Pantalon".to_string();
-                descripcion = "Jean".to_string();
-                precio = 20000;
-                categoria = Categoria::Ropa;
-                stock = 5;
+            #[ink::test]
+            fn tests_evento_categorias() {
+                let mut marketplace = Marketplace::new();
 
-                let _ = marketplace._publicar(
-                    caller,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
+                let _ = marketplace._crear_categoria(
+                    owner(),
+                    "Notebooks".to_string(),
+                    Some(COMPUTACION),
                 );
+                let _ = marketplace._renombrar_categoria(owner(), ROPA, "Indumentaria".to_string());
 
-                let _ = marketplace._ordenar_compra(caller, 1_u32);
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 2);
+                assert_eq!(eventos[0].topics[1], topic_id(4));
+                assert_eq!(eventos[1].topics[1], topic_id(ROPA));
 
-                assert!(marketplace._get_ordenes_comprador(caller).is_ok());
+                let creada: CategoriaCreada = decodificar(&eventos[0]);
+                assert_eq!(creada.nombre, "Notebooks");
+                assert_eq!(creada.id_padre, Some(COMPUTACION));
 
-                if let Ok(vec_ordenes) = marketplace._get_ordenes_comprador(caller) {
-                    assert_eq!(vec_ordenes.len(), 2);
-                }
+                let renombrada: CategoriaRenombrada = decodificar(&eventos[1]);
+                assert_eq!(renombrada.id_categoria, ROPA);
+                assert_eq!(renombrada.nombre, "Indumentaria");
             }
 
             #[ink::test]
-            fn tests_get_ordenes_comprador_usuario_no_encontrado() {
+            fn tests_evento_publicacion_creada() {
                 let mut marketplace = Marketplace::new();
 
-                let caller = AccountId::from([0xAA; 32]);
+                let vendedor = AccountId::from([0xAA; 32]);
+                let _ =
+                    marketplace._registrar_usuario(vendedor, "agustin".to_string(), Rol::Vendedor);
+                let _ = marketplace._publicar(
+                    vendedor,
+                    "Remera".to_string(),
+                    "algodon".to_string(),
+                    12000,
+                    ROPA,
+                    20,
+                );
 
-                let result = marketplace._get_ordenes_comprador(caller);
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 2);
+                assert_eq!(eventos[1].topics[1], topic(vendedor));
+
+                let evento: PublicacionCreada = decodificar(&eventos[1]);
+                assert_eq!(evento.vendedor_id, vendedor);
+                assert_eq!(evento.idx_publicacion, 0);
+                assert_eq!(evento.nombre_producto, "Remera");
+                assert_eq!(evento.precio, 12000);
+                assert_eq!(evento.id_categoria, ROPA);
+                assert_eq!(evento.stock, 20);
+            }
 
-                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+            #[ink::test]
+            fn tests_evento_orden_creada() {
+                let (_, vendedor, comprador) = crear_marketplace_con_orden();
+
+                //registro x2, publicacion, publicacion actualizada, orden creada
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 5);
+
+                let actualizada: PublicacionActualizada = decodificar(&eventos[3]);
+                assert_eq!(eventos[3].topics[1], topic(vendedor));
+                assert_eq!(actualizada.idx_publicacion, 0);
+                assert_eq!(actualizada.stock, 19);
+
+                let orden: OrdenCreada = decodificar(&eventos[4]);
+                assert_eq!(eventos[4].topics[1], topic(comprador));
+                assert_eq!(eventos[4].topics[2], topic(vendedor));
+                assert_eq!(orden.idx_orden, 0);
+                assert_eq!(orden.idx_publicacion, 0);
+                assert_eq!(orden.cantidad, 1);
+                assert_eq!(orden.monto, 12000);
             }
 
             #[ink::test]
-            fn tests_get_ordenes_comprador_usuario_no_comprador() {
-                let mut marketplace = Marketplace::new();
+            fn tests_evento_orden_enviada_y_recibida() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Vendedor;
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
 
-                let _ = marketplace._registrar_usuario(caller.clone(), username, rol);
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 7);
 
-                let result = marketplace._get_ordenes_comprador(caller);
+                let enviada: EstadoOrdenCambiado = decodificar(&eventos[5]);
+                assert_eq!(eventos[5].topics[1], topic(comprador));
+                assert_eq!(eventos[5].topics[2], topic(vendedor));
+                assert_eq!(enviada.idx_orden, 0);
+                assert_eq!(enviada.estado_anterior, Estado::Pendiente);
+                assert_eq!(enviada.estado_nuevo, Estado::Enviada);
 
-                assert_eq!(result, Err(ErrorSistema::UsuarioNoEsComprador));
+                let recibida: EstadoOrdenCambiado = decodificar(&eventos[6]);
+                assert_eq!(recibida.estado_anterior, Estado::Enviada);
+                assert_eq!(recibida.estado_nuevo, Estado::Recibida);
             }
-        }
-
-        mod tests_get_ordenes {
-            use super::*;
 
             #[ink::test]
-            fn tests_get_ordenes_correcto() {
-                let mut marketplace = Marketplace::new();
+            fn tests_evento_transicion_invalida_no_emite() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
 
-                let caller1 = AccountId::from([0xAA; 32]);
-                let username1 = "agustin".to_string();
-                let rol1 = Rol::Ambos;
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
 
-                let caller2 = AccountId::from([0xBB; 32]);
-                let username2 = "juan".to_string();
-                let rol2 = Rol::Ambos;
+                assert_eq!(eventos().len(), 5);
+            }
 
-                let _ = marketplace._registrar_usuario(caller1, username1, rol1);
-                let _ = marketplace._registrar_usuario(caller2, username2, rol2);
+            #[ink::test]
+            fn tests_evento_cancelacion_solicitada() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
 
-                let mut nombre_producto = "Remera".to_string();
-                let mut descripcion = "algodon".to_string();
-                let mut precio = 12000;
-                let mut categoria = Categoria::Ropa;
-                let mut stock = 20;
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
 
-                let _ = marketplace._publicar(
-                    caller1,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
-                );
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 6);
+                assert_eq!(eventos[5].topics[1], topic(comprador));
+                assert_eq!(eventos[5].topics[2], topic(vendedor));
 
-                let _ = marketplace._ordenar_compra(caller2, 0_u32);
+                let evento: CancelacionSolicitada = decodificar(&eventos[5]);
+                assert_eq!(evento.idx_orden, 0);
+            }
 
-                nombre_producto = "Pantalon".to_string();
-                descripcion = "Jean".to_string();
-                precio = 20000;
-                categoria = Categoria::Ropa;
-                stock = 5;
+            #[ink::test]
+            fn tests_evento_cancelacion_aceptada() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
 
-                let _ = marketplace._publicar(
-                    caller1,
-                    nombre_producto,
-                    descripcion,
-                    precio,
-                    categoria,
-                    stock,
-                );
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let _ = marketplace._aceptar_cancelacion(vendedor, 0_u32);
 
-                let _ = marketplace._ordenar_compra(caller2, 1_u32);
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 9);
 
-                assert!(marketplace._get_ordenes(caller1).is_ok());
+                let actualizada: PublicacionActualizada = decodificar(&eventos[6]);
+                assert_eq!(actualizada.stock, 20);
 
-                if let Ok(vec_ordenes) = marketplace._get_ordenes(caller1) {
-                    assert_eq!(vec_ordenes.len(), 2);
-                }
+                let cambio: EstadoOrdenCambiado = decodificar(&eventos[7]);
+                assert_eq!(cambio.estado_anterior, Estado::Pendiente);
+                assert_eq!(cambio.estado_nuevo, Estado::Cancelada);
+
+                let resuelta: CancelacionResuelta = decodificar(&eventos[8]);
+                assert_eq!(eventos[8].topics[1], topic(comprador));
+                assert_eq!(eventos[8].topics[2], topic(vendedor));
+                assert_eq!(resuelta.idx_orden, 0);
+                assert!(resuelta.aceptada);
             }
 
             #[ink::test]
-            fn tests_get_ordenes_usuario_no_encontrado() {
-                let marketplace = Marketplace::new();
+            fn tests_evento_cancelacion_rechazada() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
 
-                let caller = AccountId::from([0xAA; 32]);
+                let _ = marketplace._solicitar_cancelacion(comprador, 0_u32);
+                let _ = marketplace._rechazar_cancelacion(vendedor, 0_u32);
 
-                let result = marketplace._get_ordenes(caller);
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 7);
 
-                assert_eq!(result, Err(ErrorSistema::UsuarioNoRegistrado));
+                let resuelta: CancelacionResuelta = decodificar(&eventos[6]);
+                assert_eq!(resuelta.idx_orden, 0);
+                assert!(!resuelta.aceptada);
             }
 
             #[ink::test]
-            fn tests_get_ordenes_sin_ordenes() {
-                let mut marketplace = Marketplace::new();
+            fn tests_evento_calificacion_registrada() {
+                let (mut marketplace, vendedor, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._marcar_enviada(vendedor, 0_u32);
+                let _ = marketplace._marcar_recibida(comprador, 0_u32);
+                let _ = marketplace._calificar_vendedor(comprador, 0_u32, 5);
+                let _ = marketplace._calificar_vendedor(comprador, 0_u32, 5);
+
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 8);
+                assert_eq!(eventos[7].topics[1], topic(comprador));
+                assert_eq!(eventos[7].topics[2], topic(vendedor));
+
+                let evento: CalificacionRegistrada = decodificar(&eventos[7]);
+                assert_eq!(evento.idx_orden, 0);
+                assert_eq!(evento.puntaje, 5);
+            }
 
-                let caller = AccountId::from([0xAA; 32]);
-                let username = "agustin".to_string();
-                let rol = Rol::Ambos;
+            #[ink::test]
+            fn tests_evento_usuario_suspendido_y_levantado() {
+                let (mut marketplace, _, comprador) = crear_marketplace_con_orden();
+
+                let _ = marketplace._suspender_usuario(
+                    owner(),
+                    comprador,
+                    "abuso".to_string(),
+                    Some(10),
+                );
+                let _ = marketplace._levantar_suspension(owner(), comprador);
+                //Sin suspension vigente no se emite nada
+                let _ = marketplace._levantar_suspension(owner(), comprador);
+
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 7);
+                assert_eq!(eventos[5].topics[1], topic(comprador));
+                assert_eq!(eventos[6].topics[1], topic(comprador));
+
+                let evento: UsuarioSuspendido = decodificar(&eventos[5]);
+                assert_eq!(evento.motivo, "abuso");
+                assert_eq!(evento.hasta_bloque, Some(10));
+            }
 
-                let _ = marketplace._registrar_usuario(caller, username, rol);
+            #[ink::test]
+            fn tests_evento_publicacion_dada_de_baja() {
+                let (mut marketplace, vendedor, _) = crear_marketplace_con_orden();
 
-                let result = marketplace._get_ordenes(caller);
+                let _ = marketplace._dar_de_baja_publicacion(owner(), 0_u32, "estafa".to_string());
 
-                assert!(result.is_ok());
-                if let Ok(vec_ordenes) = result {
-                    assert_eq!(vec_ordenes.len(), 0);
-                }
+                //PublicacionActualizada y EstadoOrdenCambiado por la orden cancelada
+                let eventos = eventos();
+                assert_eq!(eventos.len(), 8);
+                assert_eq!(eventos[7].topics[1], topic(vendedor));
+
+                let estado: EstadoOrdenCambiado = decodificar(&eventos[6]);
+                assert_eq!(estado.estado_nuevo, Estado::Cancelada);
+
+                let evento: PublicacionDadaDeBaja = decodificar(&eventos[7]);
+                assert_eq!(evento.idx_publicacion, 0);
+                assert_eq!(evento.motivo, "estafa");
+                assert_eq!(evento.ordenes_canceladas, 1);
             }
         }
     }
@@ -2,138 +2,1535 @@
 
 #[ink::contract]
 mod reportes_view {
+    use core::cmp::Ordering;
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use marketplace::marketplace::{
+        Categoria, Estado, OrdenCompra, Pagina, ResumenUsuario, ResumenVentas, VentasCategoria,
+        MAX_LIMITE_PAGINA,
+    };
+    use marketplace::MarketplaceRef;
 
-    /// Defines the storage of your contract.
-    /// Add new fields to the below struct in order
-    /// to add new static storage fields to your contract.
+    //Cantidad minima de ordenes para que un vendedor entre en el ranking de
+    //confiabilidad, asi un historial corto no domina el ranking
+    pub const MIN_ORDENES_CONFIABILIDAD: u32 = 5;
+
+    //Contrato de solo lectura que calcula reportes consultando al Marketplace
     #[ink(storage)]
     pub struct ReportesView {
-        /// Stores a single `bool` value on the storage.
-        value: bool,
+        marketplace: MarketplaceRef,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct ResumenMarketplace {
+        pub cantidad_publicaciones: u32,
+        pub publicaciones_activas: u32,
+        pub cantidad_ordenes: u32,
+        pub ordenes_recibidas: u32,
+        pub volumen_vendido: Balance, // Suma de precio * cantidad de las ordenes recibidas
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct VendedorRanking {
+        pub account_id: AccountId,
+        pub username: String,
+        pub ordenes_completadas: u32,
+        pub total_vendido: Balance,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CompradorRanking {
+        pub account_id: AccountId,
+        pub username: String,
+        pub cantidad_ordenes: u32,
+        pub total_gastado: Balance,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct EstadisticasCategoria {
+        pub categoria: Categoria,
+        pub publicaciones_activas: u32,
+        pub unidades_vendidas: u64,
+        pub total_vendido: Balance,
+        pub precio_promedio_orden: Balance, // total_vendido / ordenes recibidas, 0 si no hay
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct EstadosOrdenesVendedor {
+        pub vendedor_id: AccountId,
+        pub pendientes: u32,
+        pub enviadas: u32,
+        pub recibidas: u32,
+        pub canceladas: u32,
+        pub tasa_cancelacion: u32, // Canceladas sobre el total en puntos basicos (10000 = 100%)
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ConfiabilidadVendedor {
+        pub vendedor_id: AccountId,
+        pub username: String,
+        pub total_ordenes: u32,
+        // Tasas en puntos basicos (10000 = 100%)
+        pub tasa_no_canceladas: u32,
+        pub tasa_peticiones_cancelacion: u32,
+        pub puntaje: u32, // tasa_no_canceladas - tasa_peticiones_cancelacion
     }
 
     impl ReportesView {
-        /// Constructor that initializes the `bool` value to the given `init_value`.
+        //Guarda la direccion del Marketplace sobre el que se calculan los reportes
         #[ink(constructor)]
-        pub fn new(init_value: bool) -> Self {
-            Self { value: init_value }
+        pub fn new(marketplace: AccountId) -> Self {
+            Self {
+                marketplace: ink::env::call::FromAccountId::from_account_id(marketplace),
+            }
         }
 
-        /// Constructor that initializes the `bool` value to `false`.
-        ///
-        /// Constructors can delegate to other constructors.
-        #[ink(constructor)]
-        pub fn default() -> Self {
-            Self::new(Default::default())
+        //Retorna la direccion del Marketplace consultado
+        #[ink(message)]
+        pub fn get_marketplace(&self) -> AccountId {
+            ink::ToAccountId::to_account_id(&self.marketplace)
+        }
+
+        //Retorna un resumen general de publicaciones, ordenes y ventas
+        #[ink(message)]
+        pub fn resumen_general(&self) -> ResumenMarketplace {
+            _resumen_general(&self.marketplace)
         }
 
-        /// A message that can be called on instantiated contracts.
-        /// This one flips the value of the stored `bool` from `true`
-        /// to `false` and vice versa.
+        //Retorna los `n` vendedores con mas ordenes recibidas, desempatando por
+        //monto total vendido. Recorre todos los usuarios registrados
         #[ink(message)]
-        pub fn flip(&mut self) {
-            self.value = !self.value;
+        pub fn top_vendedores(&self, n: u32) -> Vec<VendedorRanking> {
+            _top_vendedores(&self.marketplace, n)
         }
 
-        /// Simply returns the current value of our `bool`.
+        //Retorna los `n` compradores con mas ordenes no canceladas, desempatando
+        //por monto total gastado. Recorre todos los usuarios registrados
         #[ink(message)]
-        pub fn get(&self) -> bool {
-            self.value
+        pub fn top_compradores(&self, n: u32) -> Vec<CompradorRanking> {
+            _top_compradores(&self.marketplace, n)
+        }
+
+        //Retorna por cada categoria en las posiciones [offset, offset + limit) sus
+        //publicaciones activas y lo vendido en ordenes recibidas. `total` es la
+        //cantidad de categorias
+        #[ink(message)]
+        pub fn estadisticas_por_categoria(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<EstadisticasCategoria> {
+            _estadisticas_por_categoria(&self.marketplace, offset, limit)
+        }
+
+        //Retorna cuantas ordenes del vendedor hay en cada estado y su tasa de
+        //cancelacion. Recorre solo las ordenes del vendedor, por el indice que
+        //mantiene el Marketplace
+        #[ink(message)]
+        pub fn estados_ordenes_vendedor(&self, vendedor_id: AccountId) -> EstadosOrdenesVendedor {
+            _estados_ordenes_vendedor(&self.marketplace, vendedor_id)
+        }
+
+        //Retorna los `n` vendedores mas confiables segun el resultado de sus
+        //ordenes. Solo se consideran vendedores con al menos
+        //MIN_ORDENES_CONFIABILIDAD ordenes. Recorre todos los usuarios registrados
+        #[ink(message)]
+        pub fn vendedores_mas_confiables(&self, n: u32) -> Vec<ConfiabilidadVendedor> {
+            _vendedores_mas_confiables(&self.marketplace, n, MIN_ORDENES_CONFIABILIDAD)
+        }
+    }
+
+    //Consultas al Marketplace que usan los reportes. Los mensajes las hacen por
+    //MarketplaceRef y las pruebas sobre un Marketplace local, asi las dos pasan
+    //por las mismas funciones
+    trait ConsultasMarketplace {
+        fn get_resumenes_usuarios_reporte(&self, offset: u32, limit: u32)
+            -> Pagina<ResumenUsuario>;
+        fn get_ventas_categorias_reporte(&self, offset: u32, limit: u32)
+            -> Pagina<VentasCategoria>;
+        fn get_resumen_ventas_reporte(&self) -> ResumenVentas;
+        fn get_ordenes_vendedor_reporte(
+            &self,
+            vendedor_id: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<OrdenCompra>;
+    }
+
+    //Cada metodo llama al mensaje del mismo nombre en el contrato
+    impl ConsultasMarketplace for MarketplaceRef {
+        fn get_resumenes_usuarios_reporte(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<ResumenUsuario> {
+            MarketplaceRef::get_resumenes_usuarios_reporte(self, offset, limit)
+        }
+
+        fn get_ventas_categorias_reporte(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<VentasCategoria> {
+            MarketplaceRef::get_ventas_categorias_reporte(self, offset, limit)
+        }
+
+        fn get_resumen_ventas_reporte(&self) -> ResumenVentas {
+            MarketplaceRef::get_resumen_ventas_reporte(self)
+        }
+
+        fn get_ordenes_vendedor_reporte(
+            &self,
+            vendedor_id: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<OrdenCompra> {
+            MarketplaceRef::get_ordenes_vendedor_reporte(self, vendedor_id, offset, limit)
         }
     }
 
-    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
-    /// module and test functions are marked with a `#[test]` attribute.
-    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    impl ConsultasMarketplace for marketplace::Marketplace {
+        fn get_resumenes_usuarios_reporte(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<ResumenUsuario> {
+            marketplace::Marketplace::get_resumenes_usuarios_reporte(self, offset, limit)
+        }
+
+        fn get_ventas_categorias_reporte(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<VentasCategoria> {
+            marketplace::Marketplace::get_ventas_categorias_reporte(self, offset, limit)
+        }
+
+        fn get_resumen_ventas_reporte(&self) -> ResumenVentas {
+            marketplace::Marketplace::get_resumen_ventas_reporte(self)
+        }
+
+        fn get_ordenes_vendedor_reporte(
+            &self,
+            vendedor_id: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Pagina<OrdenCompra> {
+            marketplace::Marketplace::get_ordenes_vendedor_reporte(self, vendedor_id, offset, limit)
+        }
+    }
+
+    //Recorre todas las paginas de una consulta paginada y pasa los elementos de
+    //cada una a `visitar`
+    fn recorrer_paginas<T>(
+        mut consultar: impl FnMut(u32, u32) -> Pagina<T>,
+        mut visitar: impl FnMut(Vec<T>),
+    ) {
+        let mut leidos: u32 = 0;
+        loop {
+            let pagina = consultar(leidos, MAX_LIMITE_PAGINA);
+            let cantidad = pagina.elementos.len() as u32;
+            leidos = leidos.saturating_add(cantidad);
+            visitar(pagina.elementos);
+
+            if cantidad == 0 || leidos >= pagina.total {
+                return;
+            }
+        }
+    }
+
+    //Recorre todas las paginas de una consulta paginada y junta sus elementos
+    fn recolectar<T>(consultar: impl FnMut(u32, u32) -> Pagina<T>) -> Vec<T> {
+        let mut elementos = Vec::new();
+        recorrer_paginas(consultar, |pagina| elementos.extend(pagina));
+        elementos
+    }
+
+    //Recorre todos los usuarios registrados y se queda con los `n` primeros segun
+    //`comparar`. Los usuarios para los que `convertir` da None no entran. Despues
+    //de cada pagina ordena y recorta, asi nunca guarda mas de `n` mas una pagina
+    fn ranking_usuarios<T>(
+        marketplace: &impl ConsultasMarketplace,
+        n: u32,
+        convertir: impl Fn(ResumenUsuario) -> Option<T>,
+        comparar: impl Fn(&T, &T) -> Ordering,
+    ) -> Vec<T> {
+        let mut ranking = Vec::new();
+        recorrer_paginas(
+            |offset, limit| marketplace.get_resumenes_usuarios_reporte(offset, limit),
+            |usuarios| {
+                ranking.extend(usuarios.into_iter().filter_map(&convertir));
+                ranking.sort_by(&comparar);
+                ranking.truncate(n as usize);
+            },
+        );
+        ranking
+    }
+
+    //Funcion prueba resumen_general()
+    fn _resumen_general(marketplace: &impl ConsultasMarketplace) -> ResumenMarketplace {
+        let resumen = marketplace.get_resumen_ventas_reporte();
+        ResumenMarketplace {
+            cantidad_publicaciones: resumen.cantidad_publicaciones,
+            publicaciones_activas: resumen.contadores.publicaciones_activas,
+            cantidad_ordenes: resumen.cantidad_ordenes,
+            ordenes_recibidas: resumen.contadores.ordenes_recibidas,
+            volumen_vendido: resumen.contadores.total_vendido,
+        }
+    }
+
+    //Funcion prueba top_vendedores(). Arma el ranking de los usuarios con al
+    //menos una venta recibida. Ordena por cantidad de ordenes, despues por monto
+    //vendido y por ultimo por AccountId para que el resultado sea determinista
+    fn _top_vendedores(marketplace: &impl ConsultasMarketplace, n: u32) -> Vec<VendedorRanking> {
+        ranking_usuarios(
+            marketplace,
+            n,
+            |usuario| {
+                (usuario.contadores.ventas_recibidas > 0).then_some(VendedorRanking {
+                    account_id: usuario.account_id,
+                    username: usuario.username,
+                    ordenes_completadas: usuario.contadores.ventas_recibidas,
+                    total_vendido: usuario.contadores.total_vendido,
+                })
+            },
+            |a, b| {
+                b.ordenes_completadas
+                    .cmp(&a.ordenes_completadas)
+                    .then(b.total_vendido.cmp(&a.total_vendido))
+                    .then(a.account_id.cmp(&b.account_id))
+            },
+        )
+    }
+
+    //Funcion prueba estadisticas_por_categoria(). Incluye las categorias sin
+    //publicaciones. La categoria de cada venta es la de su publicacion
+    fn _estadisticas_por_categoria(
+        marketplace: &impl ConsultasMarketplace,
+        offset: u32,
+        limit: u32,
+    ) -> Pagina<EstadisticasCategoria> {
+        let categorias = marketplace.get_ventas_categorias_reporte(offset, limit);
+        let elementos = categorias
+            .elementos
+            .into_iter()
+            .map(|ventas| EstadisticasCategoria {
+                categoria: ventas.categoria,
+                publicaciones_activas: ventas.contadores.publicaciones_activas,
+                unidades_vendidas: ventas.contadores.unidades_vendidas,
+                total_vendido: ventas.contadores.total_vendido,
+                precio_promedio_orden: ventas
+                    .contadores
+                    .total_vendido
+                    .checked_div(Balance::from(ventas.contadores.ordenes_recibidas))
+                    .unwrap_or(0),
+            })
+            .collect();
+
+        Pagina {
+            elementos,
+            total: categorias.total,
+        }
+    }
+
+    //Funcion prueba estados_ordenes_vendedor(). Sin ordenes la tasa es 0
+    fn _estados_ordenes_vendedor(
+        marketplace: &impl ConsultasMarketplace,
+        vendedor_id: AccountId,
+    ) -> EstadosOrdenesVendedor {
+        let ordenes = recolectar(|offset, limit| {
+            marketplace.get_ordenes_vendedor_reporte(vendedor_id, offset, limit)
+        });
+
+        let mut estados = EstadosOrdenesVendedor {
+            vendedor_id,
+            pendientes: 0,
+            enviadas: 0,
+            recibidas: 0,
+            canceladas: 0,
+            tasa_cancelacion: 0,
+        };
+
+        for orden in ordenes {
+            let contador = match orden.estado {
+                Estado::Pendiente => &mut estados.pendientes,
+                Estado::Enviada => &mut estados.enviadas,
+                Estado::Recibida => &mut estados.recibidas,
+                Estado::Cancelada => &mut estados.canceladas,
+            };
+            *contador = contador.saturating_add(1);
+        }
+
+        let total = estados
+            .pendientes
+            .saturating_add(estados.enviadas)
+            .saturating_add(estados.recibidas)
+            .saturating_add(estados.canceladas);
+        estados.tasa_cancelacion = puntos_basicos(estados.canceladas, total);
+
+        estados
+    }
+
+    //Proporcion en puntos basicos, 0 si no hay total
+    fn puntos_basicos(parte: u32, total: u32) -> u32 {
+        (u64::from(parte) * 10000)
+            .checked_div(u64::from(total))
+            .unwrap_or(0) as u32
+    }
+
+    //Funcion prueba vendedores_mas_confiables(). Calcula la confiabilidad de los
+    //vendedores con al menos `min_ordenes` ordenes: la proporcion de
+    //ordenes no canceladas menos la proporcion en las que el comprador pidio la
+    //cancelacion, este resuelta o no. Ordena por puntaje, despues por cantidad
+    //de ordenes y por ultimo por AccountId
+    fn _vendedores_mas_confiables(
+        marketplace: &impl ConsultasMarketplace,
+        n: u32,
+        min_ordenes: u32,
+    ) -> Vec<ConfiabilidadVendedor> {
+        ranking_usuarios(
+            marketplace,
+            n,
+            |usuario| {
+                let total = usuario.contadores.ventas;
+                //Los usuarios sin ventas no entran aunque el minimo sea 0
+                if total < min_ordenes.max(1) {
+                    return None;
+                }
+                let tasa_no_canceladas = puntos_basicos(
+                    total.saturating_sub(usuario.contadores.ventas_canceladas),
+                    total,
+                );
+                let tasa_peticiones_cancelacion =
+                    puntos_basicos(usuario.contadores.ventas_con_peticion_cancelacion, total);
+                Some(ConfiabilidadVendedor {
+                    vendedor_id: usuario.account_id,
+                    username: usuario.username,
+                    total_ordenes: total,
+                    tasa_no_canceladas,
+                    tasa_peticiones_cancelacion,
+                    puntaje: tasa_no_canceladas.saturating_sub(tasa_peticiones_cancelacion),
+                })
+            },
+            |a, b| {
+                b.puntaje
+                    .cmp(&a.puntaje)
+                    .then(b.total_ordenes.cmp(&a.total_ordenes))
+                    .then(a.vendedor_id.cmp(&b.vendedor_id))
+            },
+        )
+    }
+
+    //Funcion prueba top_compradores(). Arma el ranking de los usuarios con al
+    //menos una orden no cancelada como comprador, este pendiente, enviada o
+    //recibida. Ordena por cantidad de ordenes, despues por monto gastado y por
+    //ultimo por AccountId. Los compradores con todas sus ordenes canceladas no
+    //aparecen
+    fn _top_compradores(marketplace: &impl ConsultasMarketplace, n: u32) -> Vec<CompradorRanking> {
+        ranking_usuarios(
+            marketplace,
+            n,
+            |usuario| {
+                (usuario.contadores.compras > 0).then_some(CompradorRanking {
+                    account_id: usuario.account_id,
+                    username: usuario.username,
+                    cantidad_ordenes: usuario.contadores.compras,
+                    total_gastado: usuario.contadores.total_gastado,
+                })
+            },
+            |a, b| {
+                b.cantidad_ordenes
+                    .cmp(&a.cantidad_ordenes)
+                    .then(b.total_gastado.cmp(&a.total_gastado))
+                    .then(a.account_id.cmp(&b.account_id))
+            },
+        )
+    }
+
     #[cfg(test)]
     mod tests {
-        /// Imports all the definitions from the outer scope so we can use them here.
         use super::*;
+        use marketplace::marketplace::{Publicacion, Rol};
+        use marketplace::Marketplace;
 
-        /// We test if the default constructor does its job.
-        #[ink::test]
-        fn default_works() {
-            let reportes_view = ReportesView::default();
-            assert_eq!(reportes_view.get(), false);
+        //Ids de las categorias que crea el constructor del Marketplace
+        const COMPUTACION: u32 = 0;
+        const ROPA: u32 = 1;
+        const HERRAMIENTAS: u32 = 2;
+        const MUEBLES: u32 = 3;
+
+        fn set_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
         }
 
-        /// We test a simple use case of our contract.
-        #[ink::test]
-        fn it_works() {
-            let mut reportes_view = ReportesView::new(false);
-            assert_eq!(reportes_view.get(), false);
-            reportes_view.flip();
-            assert_eq!(reportes_view.get(), true);
+        fn registrar(
+            marketplace: &mut Marketplace,
+            account_id: AccountId,
+            username: &str,
+            rol: Rol,
+        ) {
+            set_caller(account_id);
+            let _ = marketplace.registrar_usuario(username.to_string(), rol);
         }
-    }
 
+        fn publicar(
+            marketplace: &mut Marketplace,
+            vendedor: AccountId,
+            nombre_producto: &str,
+            precio: u64,
+            id_categoria: u32,
+            stock: u64,
+        ) {
+            set_caller(vendedor);
+            let _ = marketplace.publicar(
+                nombre_producto.to_string(),
+                "descripcion".to_string(),
+                precio,
+                id_categoria,
+                stock,
+            );
+        }
+
+        fn ordenar(
+            marketplace: &mut Marketplace,
+            comprador: AccountId,
+            idx_publicacion: u32,
+            cantidad: u64,
+            valor: Balance,
+        ) {
+            set_caller(comprador);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(valor);
+            let _ = marketplace.ordenar_compra(idx_publicacion, cantidad);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+        }
+
+        //Lleva una orden pendiente hasta Recibida
+        fn completar(
+            marketplace: &mut Marketplace,
+            vendedor: AccountId,
+            comprador: AccountId,
+            idx_orden: u32,
+        ) {
+            set_caller(vendedor);
+            let _ = marketplace.marcar_enviada(idx_orden);
+            set_caller(comprador);
+            let _ = marketplace.marcar_recibida(idx_orden);
+        }
+
+        //Solicita y acepta la cancelacion de una orden pendiente
+        fn cancelar(
+            marketplace: &mut Marketplace,
+            vendedor: AccountId,
+            comprador: AccountId,
+            idx_orden: u32,
+        ) {
+            set_caller(comprador);
+            let _ = marketplace.solicitar_cancelacion(idx_orden);
+            set_caller(vendedor);
+            let _ = marketplace.aceptar_cancelacion(idx_orden);
+        }
+
+        //Registra `cantidad` compradores sin actividad, para llenar paginas
+        fn registrar_usuarios_de_relleno(marketplace: &mut Marketplace, cantidad: u32) {
+            for i in 0..cantidad {
+                let mut id = [0x10; 32];
+                id[..4].copy_from_slice(&i.to_be_bytes());
+                registrar(
+                    marketplace,
+                    AccountId::from(id),
+                    &format!("relleno{}", i),
+                    Rol::Comprador,
+                );
+            }
+        }
+
+        fn publicaciones_de(marketplace: &Marketplace) -> Vec<Publicacion> {
+            recolectar(|offset, limit| marketplace.get_publicaciones_reporte(offset, limit))
+        }
+
+        fn ordenes_de(marketplace: &Marketplace) -> Vec<OrdenCompra> {
+            recolectar(|offset, limit| marketplace.get_ordenes_reporte(offset, limit))
+        }
+
+        mod tests_new {
+            use super::*;
+
+            #[ink::test]
+            fn tests_new_guarda_marketplace() {
+                let direccion = AccountId::from([0x42; 32]);
+
+                let reportes = ReportesView::new(direccion);
+
+                assert_eq!(reportes.get_marketplace(), direccion);
+            }
+        }
+
+        mod tests_recolectar {
+            use super::*;
+
+            #[ink::test]
+            fn tests_recolectar_varias_paginas() {
+                let mut marketplace = Marketplace::new();
+                let vendedor = AccountId::from([0xAA; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
 
-    /// This is how you'd write end-to-end (E2E) or integration tests for ink! contracts.
-    ///
-    /// When running these you need to make sure that you:
-    /// - Compile the tests with the `e2e-tests` feature flag enabled (`--features e2e-tests`)
-    /// - Are running a Substrate node which contains `pallet-contracts` in the background
+                let cantidad = MAX_LIMITE_PAGINA * 2 + 3;
+                for _ in 0..cantidad {
+                    publicar(&mut marketplace, vendedor, "Remera", 1000, ROPA, 1);
+                }
+
+                let publicaciones = publicaciones_de(&marketplace);
+
+                assert_eq!(publicaciones.len() as u32, cantidad);
+                assert!(publicaciones
+                    .iter()
+                    .enumerate()
+                    .all(|(i, p)| p.id_publicacion == i as u64));
+            }
+
+            #[ink::test]
+            fn tests_recolectar_marketplace_vacio() {
+                let marketplace = Marketplace::new();
+
+                assert!(publicaciones_de(&marketplace).is_empty());
+                assert!(ordenes_de(&marketplace).is_empty());
+            }
+        }
+
+        mod tests_top_vendedores {
+            use super::*;
+
+            fn top_vendedores(marketplace: &Marketplace, n: u32) -> Vec<VendedorRanking> {
+                _top_vendedores(marketplace, n)
+            }
+
+            //Tres vendedores: agustin con 2 ordenes recibidas, pedro con 2 de menor
+            //monto y maria con 1. Ademas agustin tiene una orden sin completar
+            fn crear_marketplace_con_ventas() -> (Marketplace, AccountId, AccountId, AccountId) {
+                let mut marketplace = Marketplace::new();
+
+                let agustin = AccountId::from([0xAA; 32]);
+                let pedro = AccountId::from([0xBB; 32]);
+                let maria = AccountId::from([0xCC; 32]);
+                let comprador = AccountId::from([0xDD; 32]);
+                registrar(&mut marketplace, agustin, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, pedro, "pedro", Rol::Ambos);
+                registrar(&mut marketplace, maria, "maria", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+
+                publicar(
+                    &mut marketplace,
+                    agustin,
+                    "Notebook",
+                    200000,
+                    COMPUTACION,
+                    10,
+                );
+                publicar(&mut marketplace, pedro, "Remera", 12000, ROPA, 10);
+                publicar(&mut marketplace, maria, "Silla", 5000, MUEBLES, 10);
+
+                ordenar(&mut marketplace, comprador, 0, 1, 200000);
+                ordenar(&mut marketplace, comprador, 0, 1, 200000);
+                ordenar(&mut marketplace, comprador, 0, 1, 200000);
+                ordenar(&mut marketplace, comprador, 1, 1, 12000);
+                ordenar(&mut marketplace, comprador, 1, 3, 36000);
+                ordenar(&mut marketplace, comprador, 2, 4, 20000);
+
+                completar(&mut marketplace, agustin, comprador, 0);
+                completar(&mut marketplace, agustin, comprador, 1);
+                completar(&mut marketplace, pedro, comprador, 3);
+                completar(&mut marketplace, pedro, comprador, 4);
+                completar(&mut marketplace, maria, comprador, 5);
+
+                (marketplace, agustin, pedro, maria)
+            }
+
+            #[ink::test]
+            fn tests_top_vendedores_correcto() {
+                let (marketplace, agustin, pedro, maria) = crear_marketplace_con_ventas();
+
+                let ranking = top_vendedores(&marketplace, 10);
+
+                assert_eq!(
+                    ranking,
+                    vec![
+                        VendedorRanking {
+                            account_id: agustin,
+                            username: "agustin".to_string(),
+                            ordenes_completadas: 2,
+                            total_vendido: 400000,
+                        },
+                        VendedorRanking {
+                            account_id: pedro,
+                            username: "pedro".to_string(),
+                            ordenes_completadas: 2,
+                            total_vendido: 48000,
+                        },
+                        VendedorRanking {
+                            account_id: maria,
+                            username: "maria".to_string(),
+                            ordenes_completadas: 1,
+                            total_vendido: 20000,
+                        },
+                    ]
+                );
+            }
+
+            #[ink::test]
+            fn tests_top_vendedores_limita_a_n() {
+                let (marketplace, agustin, _, _) = crear_marketplace_con_ventas();
+
+                let ranking = top_vendedores(&marketplace, 1);
+
+                assert_eq!(ranking.len(), 1);
+                assert_eq!(ranking[0].account_id, agustin);
+                assert!(top_vendedores(&marketplace, 0).is_empty());
+            }
+
+            #[ink::test]
+            fn tests_top_vendedores_empate_total_por_account_id() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor_b = AccountId::from([0xBB; 32]);
+                let vendedor_a = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xDD; 32]);
+                registrar(&mut marketplace, vendedor_b, "beto", Rol::Vendedor);
+                registrar(&mut marketplace, vendedor_a, "ana", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+
+                publicar(&mut marketplace, vendedor_b, "Remera", 12000, ROPA, 10);
+                publicar(&mut marketplace, vendedor_a, "Remera", 12000, ROPA, 10);
+                ordenar(&mut marketplace, comprador, 0, 1, 12000);
+                ordenar(&mut marketplace, comprador, 1, 1, 12000);
+                completar(&mut marketplace, vendedor_b, comprador, 0);
+                completar(&mut marketplace, vendedor_a, comprador, 1);
+
+                let ranking = top_vendedores(&marketplace, 10);
+
+                assert_eq!(ranking[0].username, "ana");
+                assert_eq!(ranking[1].username, "beto");
+            }
+
+            #[ink::test]
+            fn tests_top_vendedores_recorre_todas_las_paginas() {
+                let mut marketplace = Marketplace::new();
+
+                let comprador = AccountId::from([0xDD; 32]);
+                let agustin = AccountId::from([0xAA; 32]);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+                registrar(&mut marketplace, agustin, "agustin", Rol::Vendedor);
+                //Con estos usuarios pedro queda en la segunda pagina
+                registrar_usuarios_de_relleno(&mut marketplace, MAX_LIMITE_PAGINA);
+                let pedro = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, pedro, "pedro", Rol::Vendedor);
+
+                publicar(&mut marketplace, agustin, "Remera", 12000, ROPA, 10);
+                publicar(&mut marketplace, pedro, "Remera", 12000, ROPA, 10);
+                ordenar(&mut marketplace, comprador, 0, 1, 12000);
+                ordenar(&mut marketplace, comprador, 1, 1, 12000);
+                ordenar(&mut marketplace, comprador, 1, 1, 12000);
+                completar(&mut marketplace, agustin, comprador, 0);
+                completar(&mut marketplace, pedro, comprador, 1);
+                completar(&mut marketplace, pedro, comprador, 2);
+
+                let ranking = top_vendedores(&marketplace, 2);
+
+                assert_eq!(
+                    ranking.iter().map(|v| v.account_id).collect::<Vec<_>>(),
+                    vec![pedro, agustin]
+                );
+            }
+
+            #[ink::test]
+            fn tests_top_vendedores_sin_ordenes_recibidas() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xDD; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+                publicar(&mut marketplace, vendedor, "Remera", 12000, ROPA, 10);
+                ordenar(&mut marketplace, comprador, 0, 1, 12000);
+
+                assert!(top_vendedores(&marketplace, 10).is_empty());
+            }
+        }
+
+        mod tests_top_compradores {
+            use super::*;
+
+            fn top_compradores(marketplace: &Marketplace, n: u32) -> Vec<CompradorRanking> {
+                _top_compradores(marketplace, n)
+            }
+
+            //Un vendedor y tres compradores: juan con 2 ordenes, pedro (Ambos) con 2
+            //de menor monto y una cancelada, y lucia con 1
+            fn crear_marketplace_con_compras() -> (Marketplace, AccountId, AccountId, AccountId) {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let juan = AccountId::from([0xBB; 32]);
+                let pedro = AccountId::from([0xCC; 32]);
+                let lucia = AccountId::from([0xDD; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, juan, "juan", Rol::Comprador);
+                registrar(&mut marketplace, pedro, "pedro", Rol::Ambos);
+                registrar(&mut marketplace, lucia, "lucia", Rol::Comprador);
+
+                publicar(
+                    &mut marketplace,
+                    vendedor,
+                    "Notebook",
+                    200000,
+                    COMPUTACION,
+                    10,
+                );
+                publicar(&mut marketplace, vendedor, "Remera", 12000, ROPA, 10);
+
+                ordenar(&mut marketplace, juan, 0, 1, 200000);
+                ordenar(&mut marketplace, juan, 1, 1, 12000);
+                ordenar(&mut marketplace, pedro, 1, 2, 24000);
+                ordenar(&mut marketplace, pedro, 1, 1, 12000);
+                ordenar(&mut marketplace, pedro, 0, 1, 200000);
+                ordenar(&mut marketplace, lucia, 0, 2, 400000);
+
+                completar(&mut marketplace, vendedor, juan, 0);
+                cancelar(&mut marketplace, vendedor, pedro, 4);
+
+                (marketplace, juan, pedro, lucia)
+            }
+
+            #[ink::test]
+            fn tests_top_compradores_correcto() {
+                let (marketplace, juan, pedro, lucia) = crear_marketplace_con_compras();
+
+                let ranking = top_compradores(&marketplace, 10);
+
+                assert_eq!(
+                    ranking,
+                    vec![
+                        CompradorRanking {
+                            account_id: juan,
+                            username: "juan".to_string(),
+                            cantidad_ordenes: 2,
+                            total_gastado: 212000,
+                        },
+                        CompradorRanking {
+                            account_id: pedro,
+                            username: "pedro".to_string(),
+                            cantidad_ordenes: 2,
+                            total_gastado: 36000,
+                        },
+                        CompradorRanking {
+                            account_id: lucia,
+                            username: "lucia".to_string(),
+                            cantidad_ordenes: 1,
+                            total_gastado: 400000,
+                        },
+                    ]
+                );
+            }
+
+            #[ink::test]
+            fn tests_top_compradores_limita_a_n() {
+                let (marketplace, juan, pedro, _) = crear_marketplace_con_compras();
+
+                let ranking = top_compradores(&marketplace, 2);
+
+                assert_eq!(ranking.len(), 2);
+                assert_eq!(ranking[0].account_id, juan);
+                assert_eq!(ranking[1].account_id, pedro);
+                assert!(top_compradores(&marketplace, 0).is_empty());
+            }
+
+            #[ink::test]
+            fn tests_top_compradores_empate_total_por_account_id() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador_c = AccountId::from([0xCC; 32]);
+                let comprador_b = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, comprador_c, "carla", Rol::Ambos);
+                registrar(&mut marketplace, comprador_b, "bruno", Rol::Comprador);
+
+                publicar(&mut marketplace, vendedor, "Remera", 12000, ROPA, 10);
+                ordenar(&mut marketplace, comprador_c, 0, 1, 12000);
+                ordenar(&mut marketplace, comprador_b, 0, 1, 12000);
+
+                let ranking = top_compradores(&marketplace, 10);
+
+                assert_eq!(ranking[0].username, "bruno");
+                assert_eq!(ranking[1].username, "carla");
+            }
+
+            #[ink::test]
+            fn tests_top_compradores_excluye_solo_canceladas() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+                publicar(&mut marketplace, vendedor, "Remera", 12000, ROPA, 10);
+                ordenar(&mut marketplace, comprador, 0, 1, 12000);
+                cancelar(&mut marketplace, vendedor, comprador, 0);
+
+                assert!(top_compradores(&marketplace, 10).is_empty());
+            }
+
+            #[ink::test]
+            fn tests_top_compradores_cuenta_pendientes_y_enviadas() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+                publicar(&mut marketplace, vendedor, "Remera", 12000, ROPA, 10);
+                ordenar(&mut marketplace, comprador, 0, 1, 12000);
+                ordenar(&mut marketplace, comprador, 0, 2, 24000);
+                set_caller(vendedor);
+                let _ = marketplace.marcar_enviada(1);
+
+                let ranking = top_compradores(&marketplace, 10);
+
+                assert_eq!(ranking.len(), 1);
+                assert_eq!(ranking[0].cantidad_ordenes, 2);
+                assert_eq!(ranking[0].total_gastado, 36000);
+            }
+
+            #[ink::test]
+            fn tests_top_compradores_recorre_todas_las_paginas() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let juan = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, juan, "juan", Rol::Comprador);
+                //Con estos usuarios lucia queda en la segunda pagina
+                registrar_usuarios_de_relleno(&mut marketplace, MAX_LIMITE_PAGINA);
+                let lucia = AccountId::from([0xDD; 32]);
+                registrar(&mut marketplace, lucia, "lucia", Rol::Comprador);
+
+                publicar(&mut marketplace, vendedor, "Remera", 12000, ROPA, 10);
+                ordenar(&mut marketplace, juan, 0, 1, 12000);
+                ordenar(&mut marketplace, lucia, 0, 1, 12000);
+                ordenar(&mut marketplace, lucia, 0, 1, 12000);
+
+                let ranking = top_compradores(&marketplace, 2);
+
+                assert_eq!(
+                    ranking.iter().map(|c| c.account_id).collect::<Vec<_>>(),
+                    vec![lucia, juan]
+                );
+            }
+        }
+
+        mod tests_estadisticas_categorias {
+            use super::*;
+
+            fn estadisticas_categorias(marketplace: &Marketplace) -> Vec<EstadisticasCategoria> {
+                _estadisticas_por_categoria(marketplace, 0, MAX_LIMITE_PAGINA).elementos
+            }
+
+            #[ink::test]
+            fn tests_estadisticas_categorias_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+
+                publicar(&mut marketplace, vendedor, "Remera", 12000, ROPA, 20);
+                publicar(&mut marketplace, vendedor, "Buzo", 30000, ROPA, 5);
+                publicar(
+                    &mut marketplace,
+                    vendedor,
+                    "Notebook",
+                    200000,
+                    COMPUTACION,
+                    2,
+                );
+                publicar(&mut marketplace, vendedor, "Silla", 5000, MUEBLES, 4);
+                set_caller(vendedor);
+                let _ = marketplace.pausar_publicacion(1);
+                let _ = marketplace.pausar_publicacion(3);
+
+                ordenar(&mut marketplace, comprador, 0, 2, 24000);
+                ordenar(&mut marketplace, comprador, 1, 1, 30000);
+                ordenar(&mut marketplace, comprador, 2, 1, 200000);
+                completar(&mut marketplace, vendedor, comprador, 0);
+
+                let estadisticas = estadisticas_categorias(&marketplace);
+                let categorias = marketplace.get_categorias();
+
+                assert_eq!(
+                    estadisticas,
+                    vec![
+                        EstadisticasCategoria {
+                            categoria: categorias[COMPUTACION as usize].clone(),
+                            publicaciones_activas: 1,
+                            unidades_vendidas: 0,
+                            total_vendido: 0,
+                            precio_promedio_orden: 0,
+                        },
+                        EstadisticasCategoria {
+                            categoria: categorias[ROPA as usize].clone(),
+                            publicaciones_activas: 1,
+                            unidades_vendidas: 2,
+                            total_vendido: 24000,
+                            precio_promedio_orden: 24000,
+                        },
+                        EstadisticasCategoria {
+                            categoria: categorias[HERRAMIENTAS as usize].clone(),
+                            publicaciones_activas: 0,
+                            unidades_vendidas: 0,
+                            total_vendido: 0,
+                            precio_promedio_orden: 0,
+                        },
+                        EstadisticasCategoria {
+                            categoria: categorias[MUEBLES as usize].clone(),
+                            publicaciones_activas: 0,
+                            unidades_vendidas: 0,
+                            total_vendido: 0,
+                            precio_promedio_orden: 0,
+                        },
+                    ]
+                );
+            }
+
+            #[ink::test]
+            fn tests_estadisticas_categorias_precio_promedio() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+
+                publicar(
+                    &mut marketplace,
+                    vendedor,
+                    "Martillo",
+                    1000,
+                    HERRAMIENTAS,
+                    10,
+                );
+                publicar(
+                    &mut marketplace,
+                    vendedor,
+                    "Taladro",
+                    8000,
+                    HERRAMIENTAS,
+                    10,
+                );
+                ordenar(&mut marketplace, comprador, 0, 3, 3000);
+                ordenar(&mut marketplace, comprador, 1, 1, 8000);
+                completar(&mut marketplace, vendedor, comprador, 0);
+                completar(&mut marketplace, vendedor, comprador, 1);
+
+                let estadisticas = estadisticas_categorias(&marketplace);
+                let herramientas = &estadisticas[2];
+
+                assert_eq!(herramientas.categoria.id_categoria, HERRAMIENTAS);
+                assert_eq!(herramientas.publicaciones_activas, 2);
+                assert_eq!(herramientas.unidades_vendidas, 4);
+                assert_eq!(herramientas.total_vendido, 11000);
+                assert_eq!(herramientas.precio_promedio_orden, 5500);
+            }
+
+            #[ink::test]
+            fn tests_estadisticas_categorias_incluye_categorias_creadas() {
+                let mut marketplace = Marketplace::new();
+                let admin =
+                    ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice;
+                set_caller(admin);
+                let electro = marketplace
+                    .crear_categoria("Electrodomesticos".to_string(), None)
+                    .unwrap();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                publicar(
+                    &mut marketplace,
+                    vendedor,
+                    "Heladera",
+                    500000,
+                    electro.id_categoria,
+                    2,
+                );
+
+                let estadisticas = estadisticas_categorias(&marketplace);
+
+                assert_eq!(estadisticas.len(), 5);
+                assert_eq!(estadisticas[4].categoria, electro);
+                assert_eq!(estadisticas[4].publicaciones_activas, 1);
+            }
+
+            #[ink::test]
+            fn tests_estadisticas_categorias_paginado() {
+                let marketplace = Marketplace::new();
+
+                let pagina = _estadisticas_por_categoria(&marketplace, 3, 10);
+
+                assert_eq!(pagina.total, 4);
+                assert_eq!(pagina.elementos.len(), 1);
+                assert_eq!(pagina.elementos[0].categoria.id_categoria, MUEBLES);
+            }
+        }
+
+        mod tests_estados_ordenes_vendedor {
+            use super::*;
+
+            #[ink::test]
+            fn tests_estados_ordenes_vendedor_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let otro_vendedor = AccountId::from([0xCC; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, otro_vendedor, "pedro", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+
+                publicar(&mut marketplace, vendedor, "Remera", 12000, ROPA, 20);
+                publicar(&mut marketplace, otro_vendedor, "Silla", 5000, MUEBLES, 4);
+
+                for _ in 0..4 {
+                    ordenar(&mut marketplace, comprador, 0, 1, 12000);
+                }
+                ordenar(&mut marketplace, comprador, 1, 1, 5000);
+                completar(&mut marketplace, vendedor, comprador, 0);
+                set_caller(vendedor);
+                let _ = marketplace.marcar_enviada(1);
+                cancelar(&mut marketplace, vendedor, comprador, 2);
+                cancelar(&mut marketplace, otro_vendedor, comprador, 4);
+
+                let estados = _estados_ordenes_vendedor(&marketplace, vendedor);
+
+                assert_eq!(
+                    estados,
+                    EstadosOrdenesVendedor {
+                        vendedor_id: vendedor,
+                        pendientes: 1,
+                        enviadas: 1,
+                        recibidas: 1,
+                        canceladas: 1,
+                        tasa_cancelacion: 2500,
+                    }
+                );
+            }
+
+            #[ink::test]
+            fn tests_estados_ordenes_vendedor_sin_ordenes() {
+                let marketplace = Marketplace::new();
+                let vendedor = AccountId::from([0xAA; 32]);
+
+                let estados = _estados_ordenes_vendedor(&marketplace, vendedor);
+
+                assert_eq!(estados.pendientes, 0);
+                assert_eq!(estados.canceladas, 0);
+                assert_eq!(estados.tasa_cancelacion, 0);
+            }
+        }
+
+        mod tests_vendedores_confiables {
+            use super::*;
+
+            fn vendedores_confiables(
+                marketplace: &Marketplace,
+                n: u32,
+                min_ordenes: u32,
+            ) -> Vec<ConfiabilidadVendedor> {
+                _vendedores_mas_confiables(marketplace, n, min_ordenes)
+            }
+
+            //agustin: 4 ordenes, 1 cancelada. pedro: 4 ordenes, 1 con peticion de
+            //cancelacion abierta. maria: 4 ordenes sin problemas. lucas: 1 orden
+            fn crear_marketplace_con_historial() -> (Marketplace, [AccountId; 4]) {
+                let mut marketplace = Marketplace::new();
+
+                let agustin = AccountId::from([0xA1; 32]);
+                let pedro = AccountId::from([0xA2; 32]);
+                let maria = AccountId::from([0xA3; 32]);
+                let lucas = AccountId::from([0xA4; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, agustin, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, pedro, "pedro", Rol::Ambos);
+                registrar(&mut marketplace, maria, "maria", Rol::Vendedor);
+                registrar(&mut marketplace, lucas, "lucas", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+
+                for (idx, vendedor) in [agustin, pedro, maria, lucas].into_iter().enumerate() {
+                    publicar(&mut marketplace, vendedor, "Remera", 1000, ROPA, 10);
+                    let ordenes = if vendedor == lucas { 1 } else { 4 };
+                    for _ in 0..ordenes {
+                        ordenar(&mut marketplace, comprador, idx as u32, 1, 1000);
+                    }
+                }
+                //Ordenes 0..4 de agustin, 4..8 de pedro, 8..12 de maria, 12 de lucas
+                cancelar(&mut marketplace, agustin, comprador, 0);
+                set_caller(comprador);
+                let _ = marketplace.solicitar_cancelacion(4);
+                completar(&mut marketplace, maria, comprador, 8);
+
+                (marketplace, [agustin, pedro, maria, lucas])
+            }
+
+            #[ink::test]
+            fn tests_vendedores_confiables_correcto() {
+                let (marketplace, [agustin, pedro, maria, _]) = crear_marketplace_con_historial();
+
+                let ranking = vendedores_confiables(&marketplace, 10, 2);
+
+                assert_eq!(
+                    ranking,
+                    vec![
+                        ConfiabilidadVendedor {
+                            vendedor_id: maria,
+                            username: "maria".to_string(),
+                            total_ordenes: 4,
+                            tasa_no_canceladas: 10000,
+                            tasa_peticiones_cancelacion: 0,
+                            puntaje: 10000,
+                        },
+                        ConfiabilidadVendedor {
+                            vendedor_id: pedro,
+                            username: "pedro".to_string(),
+                            total_ordenes: 4,
+                            tasa_no_canceladas: 10000,
+                            tasa_peticiones_cancelacion: 2500,
+                            puntaje: 7500,
+                        },
+                        //La cancelacion de agustin paso por una peticion, que tambien cuenta
+                        ConfiabilidadVendedor {
+                            vendedor_id: agustin,
+                            username: "agustin".to_string(),
+                            total_ordenes: 4,
+                            tasa_no_canceladas: 7500,
+                            tasa_peticiones_cancelacion: 2500,
+                            puntaje: 5000,
+                        },
+                    ]
+                );
+            }
+
+            #[ink::test]
+            fn tests_vendedores_confiables_cuenta_peticiones_resueltas() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+                publicar(&mut marketplace, vendedor, "Remera", 1000, ROPA, 10);
+                for _ in 0..4 {
+                    ordenar(&mut marketplace, comprador, 0, 1, 1000);
+                }
+
+                //Una peticion rechazada y otra aceptada: ya no queda ninguna abierta
+                set_caller(comprador);
+                let _ = marketplace.solicitar_cancelacion(0);
+                set_caller(vendedor);
+                let _ = marketplace.rechazar_cancelacion(0);
+                cancelar(&mut marketplace, vendedor, comprador, 1);
+
+                let ranking = vendedores_confiables(&marketplace, 10, 1);
+
+                assert_eq!(ranking[0].tasa_no_canceladas, 7500);
+                assert_eq!(ranking[0].tasa_peticiones_cancelacion, 5000);
+                assert_eq!(ranking[0].puntaje, 2500);
+            }
+
+            #[ink::test]
+            fn tests_vendedores_confiables_minimo_de_ordenes() {
+                let (marketplace, [_, _, maria, lucas]) = crear_marketplace_con_historial();
+
+                //Con el mismo puntaje desempata la cantidad de ordenes
+                let ranking = vendedores_confiables(&marketplace, 10, 1);
+                assert_eq!(ranking.len(), 4);
+                assert_eq!(ranking[0].vendedor_id, maria);
+                assert_eq!(ranking[1].vendedor_id, lucas);
+
+                assert!(vendedores_confiables(&marketplace, 10, 5).is_empty());
+            }
+
+            #[ink::test]
+            fn tests_vendedores_confiables_recorre_todas_las_paginas() {
+                let mut marketplace = Marketplace::new();
+
+                let comprador = AccountId::from([0xDD; 32]);
+                let agustin = AccountId::from([0xAA; 32]);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+                registrar(&mut marketplace, agustin, "agustin", Rol::Vendedor);
+                //Con estos usuarios pedro queda en la segunda pagina
+                registrar_usuarios_de_relleno(&mut marketplace, MAX_LIMITE_PAGINA);
+                let pedro = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, pedro, "pedro", Rol::Vendedor);
+
+                publicar(&mut marketplace, agustin, "Remera", 12000, ROPA, 10);
+                publicar(&mut marketplace, pedro, "Remera", 12000, ROPA, 10);
+                ordenar(&mut marketplace, comprador, 0, 1, 12000);
+                ordenar(&mut marketplace, comprador, 1, 1, 12000);
+                ordenar(&mut marketplace, comprador, 1, 1, 12000);
+
+                //El minimo se aplica a cada vendedor: agustin con 1 orden no entra
+                let ranking = vendedores_confiables(&marketplace, 10, 2);
+
+                assert_eq!(ranking.len(), 1);
+                assert_eq!(ranking[0].vendedor_id, pedro);
+                assert_eq!(vendedores_confiables(&marketplace, 10, 1).len(), 2);
+            }
+
+            #[ink::test]
+            fn tests_vendedores_confiables_limita_a_n() {
+                let (marketplace, [_, _, maria, _]) = crear_marketplace_con_historial();
+
+                let ranking = vendedores_confiables(&marketplace, 1, 2);
+
+                assert_eq!(ranking.len(), 1);
+                assert_eq!(ranking[0].vendedor_id, maria);
+            }
+        }
+
+        mod tests_resumen {
+            use super::*;
+
+            #[ink::test]
+            fn tests_resumen_correcto() {
+                let mut marketplace = Marketplace::new();
+
+                let vendedor = AccountId::from([0xAA; 32]);
+                let comprador = AccountId::from([0xBB; 32]);
+                registrar(&mut marketplace, vendedor, "agustin", Rol::Vendedor);
+                registrar(&mut marketplace, comprador, "juan", Rol::Comprador);
+
+                publicar(&mut marketplace, vendedor, "Remera", 12000, ROPA, 20);
+                publicar(&mut marketplace, vendedor, "Silla", 5000, MUEBLES, 4);
+                set_caller(vendedor);
+                let _ = marketplace.pausar_publicacion(1);
+
+                ordenar(&mut marketplace, comprador, 0, 2, 24000);
+                ordenar(&mut marketplace, comprador, 0, 1, 12000);
+                completar(&mut marketplace, vendedor, comprador, 0);
+
+                let resumen = _resumen_general(&marketplace);
+
+                assert_eq!(
+                    resumen,
+                    ResumenMarketplace {
+                        cantidad_publicaciones: 2,
+                        publicaciones_activas: 1,
+                        cantidad_ordenes: 2,
+                        ordenes_recibidas: 1,
+                        volumen_vendido: 24000,
+                    }
+                );
+            }
+
+            #[ink::test]
+            fn tests_resumen_vacio() {
+                let marketplace = Marketplace::new();
+
+                let resumen = _resumen_general(&marketplace);
+
+                assert_eq!(resumen, ResumenMarketplace::default());
+            }
+        }
+    }
+
+    //Pruebas end-to-end: despliegan el Marketplace y el ReportesView en un nodo y
+    //consultan los reportes por llamadas entre contratos. Se compilan con
+    //`--features e2e-tests` y necesitan un nodo con `pallet-contracts` corriendo
     #[cfg(all(test, feature = "e2e-tests"))]
     mod e2e_tests {
-        /// Imports all the definitions from the outer scope so we can use them here.
         use super::*;
-
-        /// A helper function used for calling contract messages.
         use ink_e2e::ContractsBackend;
+        use marketplace::marketplace::Rol;
+        use marketplace::Marketplace;
 
-        /// The End-to-End test `Result` type.
         type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
-        /// We test that we can upload and instantiate the contract using its default constructor.
+        //bob vende una remera a charlie en dos ordenes: la primera se recibe y de la
+        //segunda se pide la cancelacion. Despues se consultan todos los reportes
         #[ink_e2e::test]
-        async fn default_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
-            // Given
-            let mut constructor = ReportesViewRef::default();
+        async fn e2e_reportes_sobre_marketplace(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let vendedor = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let comprador = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
 
-            // When
-            let contract = client
-                .instantiate("reportes_view", &ink_e2e::alice(), &mut constructor)
+            let mut constructor = MarketplaceRef::new();
+            let marketplace = client
+                .instantiate("marketplace", &ink_e2e::alice(), &mut constructor)
                 .submit()
                 .await
-                .expect("instantiate failed");
-            let call_builder = contract.call_builder::<ReportesView>();
-
-            // Then
-            let get = call_builder.get();
-            let get_result = client.call(&ink_e2e::alice(), &get).dry_run().await?;
-            assert!(matches!(get_result.return_value(), false));
+                .expect("instanciar marketplace fallo");
+            let mut llamadas = marketplace.call_builder::<Marketplace>();
 
-            Ok(())
-        }
-
-        /// We test that we can read and write a value from the on-chain contract.
-        #[ink_e2e::test]
-        async fn it_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
-            // Given
-            let mut constructor = ReportesViewRef::new(false);
-            let contract = client
-                .instantiate("reportes_view", &ink_e2e::bob(), &mut constructor)
+            client
+                .call(
+                    &ink_e2e::bob(),
+                    &llamadas.registrar_usuario("agustin".to_string(), Rol::Vendedor),
+                )
                 .submit()
                 .await
-                .expect("instantiate failed");
-            let mut call_builder = contract.call_builder::<ReportesView>();
-
-            let get = call_builder.get();
-            let get_result = client.call(&ink_e2e::bob(), &get).dry_run().await?;
-            assert!(matches!(get_result.return_value(), false));
+                .expect("registrar vendedor fallo");
+            client
+                .call(
+                    &ink_e2e::charlie(),
+                    &llamadas.registrar_usuario("juan".to_string(), Rol::Comprador),
+                )
+                .submit()
+                .await
+                .expect("registrar comprador fallo");
+            client
+                .call(
+                    &ink_e2e::bob(),
+                    &llamadas.publicar("Remera".to_string(), "algodon".to_string(), 1000, 1, 10),
+                )
+                .submit()
+                .await
+                .expect("publicar fallo");
+            client
+                .call(&ink_e2e::charlie(), &llamadas.ordenar_compra(0, 2))
+                .value(2000)
+                .submit()
+                .await
+                .expect("ordenar compra fallo");
+            client
+                .call(&ink_e2e::charlie(), &llamadas.ordenar_compra(0, 1))
+                .value(1000)
+                .submit()
+                .await
+                .expect("ordenar compra fallo");
+            client
+                .call(&ink_e2e::bob(), &llamadas.marcar_enviada(0))
+                .submit()
+                .await
+                .expect("marcar enviada fallo");
+            client
+                .call(&ink_e2e::charlie(), &llamadas.marcar_recibida(0))
+                .submit()
+                .await
+                .expect("marcar recibida fallo");
+            client
+                .call(&ink_e2e::charlie(), &llamadas.solicitar_cancelacion(1))
+                .submit()
+                .await
+                .expect("solicitar cancelacion fallo");
 
-            // When
-            let flip = call_builder.flip();
-            let _flip_result = client
-                .call(&ink_e2e::bob(), &flip)
+            let mut constructor = ReportesViewRef::new(marketplace.account_id);
+            let reportes = client
+                .instantiate("reportes_view", &ink_e2e::alice(), &mut constructor)
                 .submit()
                 .await
-                .expect("flip failed");
+                .expect("instanciar reportes fallo");
+            let reportes = reportes.call_builder::<ReportesView>();
+
+            let direccion = client
+                .call(&ink_e2e::alice(), &reportes.get_marketplace())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(direccion, marketplace.account_id);
+
+            let resumen = client
+                .call(&ink_e2e::alice(), &reportes.resumen_general())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(
+                resumen,
+                ResumenMarketplace {
+                    cantidad_publicaciones: 1,
+                    publicaciones_activas: 1,
+                    cantidad_ordenes: 2,
+                    ordenes_recibidas: 1,
+                    volumen_vendido: 2000,
+                }
+            );
+
+            let vendedores = client
+                .call(&ink_e2e::alice(), &reportes.top_vendedores(10))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(
+                vendedores,
+                vec![VendedorRanking {
+                    account_id: vendedor,
+                    username: "agustin".to_string(),
+                    ordenes_completadas: 1,
+                    total_vendido: 2000,
+                }]
+            );
+
+            let compradores = client
+                .call(&ink_e2e::alice(), &reportes.top_compradores(10))
+                .dry_run()
+                .await?
+                .return_value();
+            //La orden con la cancelacion pedida todavia cuenta
+            assert_eq!(
+                compradores,
+                vec![CompradorRanking {
+                    account_id: comprador,
+                    username: "juan".to_string(),
+                    cantidad_ordenes: 2,
+                    total_gastado: 3000,
+                }]
+            );
+
+            let categorias = client
+                .call(
+                    &ink_e2e::alice(),
+                    &reportes.estadisticas_por_categoria(0, 10),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(categorias.total, 4);
+            assert_eq!(categorias.elementos[1].publicaciones_activas, 1);
+            assert_eq!(categorias.elementos[1].unidades_vendidas, 2);
+            assert_eq!(categorias.elementos[1].total_vendido, 2000);
+
+            let estados = client
+                .call(
+                    &ink_e2e::alice(),
+                    &reportes.estados_ordenes_vendedor(vendedor),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(
+                estados,
+                EstadosOrdenesVendedor {
+                    vendedor_id: vendedor,
+                    pendientes: 1,
+                    enviadas: 0,
+                    recibidas: 1,
+                    canceladas: 0,
+                    tasa_cancelacion: 0,
+                }
+            );
 
-            // Then
-            let get = call_builder.get();
-            let get_result = client.call(&ink_e2e::bob(), &get).dry_run().await?;
-            assert!(matches!(get_result.return_value(), true));
+            //Con 2 ordenes bob no llega a MIN_ORDENES_CONFIABILIDAD
+            let confiables = client
+                .call(&ink_e2e::alice(), &reportes.vendedores_mas_confiables(10))
+                .dry_run()
+                .await?
+                .return_value();
+            assert!(confiables.is_empty());
 
             Ok(())
         }